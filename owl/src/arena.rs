@@ -0,0 +1,367 @@
+use std::ops::Range;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use crate::{prelude::*, ArrayBuffer, AttributePointer, BufferUsage, ElementBuffer, Mesh, OwlError,
+    ThinInputAttribute, VertexArray};
+
+/// Distinguishes the handles of different arenas
+static NEXT_ARENA: AtomicUsize = AtomicUsize::new(0);
+
+/// The ranges of a buffer that are not allocated, sorted and coalesced
+#[derive(Debug, Default)]
+struct FreeList(Vec<Range<usize>>);
+impl FreeList {
+    /// Take the first free range that fits `length`, returning its start
+    fn allocate(&mut self, length: usize) -> Option<usize> {
+        if length == 0 {
+            return Some(0);
+        }
+        let index = self.0.iter().position(|free| free.len() >= length)?;
+        let start = self.0[index].start;
+        self.0[index].start += length;
+        if self.0[index].is_empty() {
+            self.0.remove(index);
+        }
+        Some(start)
+    }
+    /// Return `range` to the list, merging it with its neighbours
+    fn free(&mut self, range: Range<usize>) {
+        if range.is_empty() {
+            return;
+        }
+        let index = self.0.partition_point(|free| free.start < range.start);
+        self.0.insert(index, range);
+        if index + 1 < self.0.len() && self.0[index].end == self.0[index + 1].start {
+            self.0[index].end = self.0.remove(index + 1).end;
+        }
+        if index > 0 && self.0[index - 1].end == self.0[index].start {
+            self.0[index - 1].end = self.0.remove(index).end;
+        }
+    }
+}
+
+/// Where each live allocation's range, selected by `range`, moves to when they are packed
+/// from the start of the page in their current order
+fn compacted(slots: &[Option<Allocation>], range: impl Fn(&Allocation) -> &Range<usize>)
+    -> Vec<(usize, Range<usize>, Range<usize>)> {
+    let mut live: Vec<(usize, Range<usize>)> = slots.iter().enumerate()
+        .filter_map(|(slot, allocation)| allocation.as_ref().map(|a| (slot, range(a).clone())))
+        .collect();
+    live.sort_by_key(|(_, from)| from.start);
+    let mut cursor = 0;
+    live.into_iter().map(|(slot, from)| {
+        let to = cursor..cursor + from.len();
+        cursor = to.end;
+        (slot, from, to)
+    }).collect()
+}
+
+/// The ranges of the arena's pages holding a mesh
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Allocation {
+    vertices: Range<usize>,
+    indices: Range<usize>,
+}
+
+/// A handle to a mesh allocated in a [`BufferArena`], to draw it with [`BufferArena::mesh`].
+///
+/// It is not Clone, so that a mesh can only be freed once.
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct ArenaMesh {
+    arena: usize,
+    slot: usize,
+}
+
+/// Many meshes with the same vertex layout, sub-allocated from a single page of vertices and a single
+/// page of indices, so that they share one [`VertexArray`] and are drawn without rebinding it.
+///
+/// The indices of each mesh count from its own first vertex, and are drawn with a base vertex, so
+/// meshes can be moved by [`BufferArena::defragment`] without rewriting them. The pages grow as needed,
+/// keeping their GL buffers, so the vertex array stays valid.
+pub struct BufferArena<T: Pod, E: IndexElement = u32> {
+    id: usize,
+    vertices: ArrayBuffer<T>,
+    /// Owns the page of indices
+    vertex_array: VertexArray<E>,
+    free_vertices: FreeList,
+    free_indices: FreeList,
+    slots: Vec<Option<Allocation>>,
+    free_slots: Vec<usize>,
+}
+impl<T: Pod, E: IndexElement> BufferArena<T, E> {
+    /// Create an arena with pages of `vertex_capacity` vertices and `index_capacity` indices,
+    /// to which inputs should then be added
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if we are out of memory.
+    pub fn new(vertex_capacity: usize, index_capacity: usize, usage: BufferUsage) -> Result<Self, OwlError> {
+        let vertices = ArrayBuffer::from_slice(&vec![Self::zeroed_vertex(); vertex_capacity], usage)
+            .with_context("creating BufferArena")?;
+        let indices = ElementBuffer::new(&vec![Self::zeroed_index(); index_capacity], usage)
+            .with_context("creating BufferArena")?;
+        let mut free_vertices = FreeList::default();
+        free_vertices.free(0..vertex_capacity);
+        let mut free_indices = FreeList::default();
+        free_indices.free(0..index_capacity);
+        Ok(Self {
+            id: NEXT_ARENA.fetch_add(1, Ordering::Relaxed),
+            vertices,
+            vertex_array: VertexArray::new().with_indices(indices),
+            free_vertices,
+            free_indices,
+            slots: Vec::new(),
+            free_slots: Vec::new(),
+        })
+    }
+    /// Add an input, read from the page of vertices with the given stride and offset
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the maximum number of inputs is exceeded.
+    pub fn with_input(mut self, attribute: ThinInputAttribute, stride: Bytes, offset: Bytes) -> Result<Self,OwlError> {
+        let pointer = AttributePointer { buffer: &self.vertices, stride, offset };
+        self.vertex_array = self.vertex_array.with_input(attribute, pointer)
+            .with_context("adding input to BufferArena")?;
+        Ok(self)
+    }
+    /// Add an array input, read from the page of vertices with the given stride and offset
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the maximum number of inputs is exceeded.
+    pub fn with_input_array(mut self, length: u8, attribute: ThinInputAttribute, stride: Bytes, offset: Bytes)
+        -> Result<Self,OwlError> {
+        let pointer = AttributePointer { buffer: &self.vertices, stride, offset };
+        self.vertex_array = self.vertex_array.with_input_array(length, attribute, pointer)
+            .with_context("adding input array to BufferArena")?;
+        Ok(self)
+    }
+    /// The vertex array shared by every mesh, e.g. for [`crate::ShaderPipeline::inputs_from_vertex_array`]
+    #[must_use]
+    pub const fn vertex_array(&self) -> &VertexArray<E> {
+        &self.vertex_array
+    }
+    /// The number of vertices the page has space for, before it must grow
+    #[must_use]
+    pub const fn vertex_capacity(&self) -> usize {
+        self.vertices.len()
+    }
+    /// The number of indices the page has space for, before it must grow
+    #[must_use]
+    pub fn index_capacity(&self) -> usize {
+        self.vertex_array.elements.as_ref().map_or(0, ElementBuffer::len)
+    }
+    /// Upload a mesh into free ranges of the pages, growing them if there are none large enough.
+    /// The indices count from the mesh's first vertex.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `indices` is empty, the pages must grow but we are
+    /// out of memory, or either page is being mapped.
+    pub fn allocate(&mut self, vertices: &[T], indices: &[E]) -> Result<ArenaMesh, OwlError> {
+        if indices.is_empty() {
+            return Err(OwlError::custom("arena meshes must have indices"))
+                .with_context("allocating in BufferArena");
+        }
+        let vertex_start = self.allocate_vertices(vertices.len()).with_context("allocating in BufferArena")?;
+        let vertex_range = vertex_start..vertex_start + vertices.len();
+        let index_start = match self.allocate_indices(indices.len()) {
+            Ok(start) => start,
+            Err(e) => {
+                self.free_vertices.free(vertex_range);
+                return Err(e).with_context("allocating in BufferArena");
+            },
+        };
+        let index_range = index_start..index_start + indices.len();
+        let uploaded = self.vertices.update_from_slice(vertices, vertex_start)
+            .and_then(|()| self.indices_mut().update(indices, index_start));
+        if let Err(e) = uploaded {
+            self.free_vertices.free(vertex_range);
+            self.free_indices.free(index_range);
+            return Err(e).with_context("allocating in BufferArena");
+        }
+        let allocation = Allocation { vertices: vertex_range, indices: index_range };
+        let slot = if let Some(slot) = self.free_slots.pop() {
+            self.slots[slot] = Some(allocation);
+            slot
+        } else {
+            self.slots.push(Some(allocation));
+            self.slots.len() - 1
+        };
+        Ok(ArenaMesh { arena: self.id, slot })
+    }
+    /// Return a mesh's ranges to the pages, to be reused by later allocations
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the mesh was allocated by another arena.
+    #[allow(clippy::needless_pass_by_value)]     // the handle must not be used once freed
+    pub fn free(&mut self, mesh: ArenaMesh) -> Result<(),OwlError> {
+        let allocation = self.allocation(&mesh).with_context("freeing from BufferArena")?.clone();
+        self.free_vertices.free(allocation.vertices);
+        self.free_indices.free(allocation.indices);
+        self.slots[mesh.slot] = None;
+        self.free_slots.push(mesh.slot);
+        Ok(())
+    }
+    /// The mesh, to be drawn with the arena's vertex array
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the mesh was allocated by another arena.
+    pub fn mesh(&self, mesh: &ArenaMesh) -> Result<Mesh<'_, E>, OwlError> {
+        let allocation = self.allocation(mesh).with_context("getting mesh from BufferArena")?;
+        Ok(Mesh {
+            start: allocation.indices.start,
+            count: allocation.indices.len(),
+            base_vertex: allocation.vertices.start,
+            vertex_array: &self.vertex_array,
+        })
+    }
+    /// Move every mesh to the start of the pages, so that their free space is contiguous.
+    /// Meshes are moved on the GPU, through a temporary copy of each page.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if we are out of memory, or either page is being mapped.
+    pub fn defragment(&mut self) -> Result<(),OwlError> {
+        let vertex_moves = compacted(&self.slots, |allocation| &allocation.vertices);
+        let index_moves = compacted(&self.slots, |allocation| &allocation.indices);
+        if vertex_moves.iter().any(|(_, from, to)| from != to) {
+            let scratch = self.vertices.duplicate().with_context("defragmenting BufferArena")?;
+            for (_, from, to) in vertex_moves.iter().filter(|(_, from, to)| from != to) {
+                self.vertices.copy_from(&scratch, from.start, to.start, from.len())
+                    .with_context("defragmenting BufferArena")?;
+            }
+        }
+        if index_moves.iter().any(|(_, from, to)| from != to) {
+            let scratch = self.indices_mut().duplicate().with_context("defragmenting BufferArena")?;
+            for (_, from, to) in index_moves.iter().filter(|(_, from, to)| from != to) {
+                self.indices_mut().copy_from(&scratch, from.start, to.start, from.len())
+                    .with_context("defragmenting BufferArena")?;
+            }
+        }
+        // only once every copy has succeeded, so that the handles still match the pages if one fails
+        for (slot, _, to) in vertex_moves.iter().cloned() {
+            self.slots[slot].as_mut().expect("slot is live").vertices = to;
+        }
+        for (slot, _, to) in index_moves.iter().cloned() {
+            self.slots[slot].as_mut().expect("slot is live").indices = to;
+        }
+        self.free_vertices = FreeList::default();
+        self.free_vertices.free(vertex_moves.last().map_or(0, |(_, _, to)| to.end)..self.vertex_capacity());
+        self.free_indices = FreeList::default();
+        self.free_indices.free(index_moves.last().map_or(0, |(_, _, to)| to.end)..self.index_capacity());
+        Ok(())
+    }
+    /// # Errors
+    /// This function will return an error if the mesh was allocated by another arena.
+    fn allocation(&self, mesh: &ArenaMesh) -> Result<&Allocation, OwlError> {
+        if mesh.arena != self.id {
+            return Err(OwlError::custom("mesh was allocated by another arena"));
+        }
+        Ok(self.slots[mesh.slot].as_ref().expect("handles are only freed once"))
+    }
+    /// # Errors
+    /// This function will return an error if the page must grow, but we are out of memory.
+    fn allocate_vertices(&mut self, length: usize) -> Result<usize, OwlError> {
+        if let Some(start) = self.free_vertices.allocate(length) {
+            return Ok(start);
+        }
+        let capacity = self.vertex_capacity();
+        let grown = (capacity * 2).max(capacity + length);
+        self.vertices.resize(grown, Self::zeroed_vertex()).with_context("growing vertex page")?;
+        self.free_vertices.free(capacity..grown);
+        Ok(self.free_vertices.allocate(length).expect("page grew to fit"))
+    }
+    /// # Errors
+    /// This function will return an error if the page must grow, but we are out of memory.
+    fn allocate_indices(&mut self, length: usize) -> Result<usize, OwlError> {
+        if let Some(start) = self.free_indices.allocate(length) {
+            return Ok(start);
+        }
+        let capacity = self.index_capacity();
+        let grown = (capacity * 2).max(capacity + length);
+        self.indices_mut().resize(grown, Self::zeroed_index()).with_context("growing index page")?;
+        self.free_indices.free(capacity..grown);
+        Ok(self.free_indices.allocate(length).expect("page grew to fit"))
+    }
+    const fn indices_mut(&mut self) -> &mut ElementBuffer<E> {
+        self.vertex_array.indices_mut().expect("arena vertex arrays always have indices")
+    }
+    const fn zeroed_vertex() -> T {
+        // SAFETY: T is Pod, so valid for any bit pattern, including zero
+        unsafe { std::mem::zeroed() }
+    }
+    const fn zeroed_index() -> E {
+        // SAFETY: E is Pod, so valid for any bit pattern, including zero
+        unsafe { std::mem::zeroed() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn free_list(ranges: &[Range<usize>]) -> FreeList {
+        let mut list = FreeList::default();
+        for range in ranges {
+            list.free(range.clone());
+        }
+        list
+    }
+
+    #[test]
+    fn allocate_takes_first_fit() {
+        let mut list = free_list(&[0..2, 4..10, 12..20]);
+        assert_eq!(list.allocate(3), Some(4));
+        assert_eq!(list.0, [0..2, 7..10, 12..20]);
+        assert_eq!(list.allocate(2), Some(0));
+        assert_eq!(list.0, [7..10, 12..20]);
+        assert_eq!(list.allocate(4), Some(12));
+        assert_eq!(list.allocate(5), None);
+        assert_eq!(list.0, [7..10, 16..20]);
+    }
+
+    #[test]
+    fn allocate_nothing() {
+        let mut list = FreeList::default();
+        assert_eq!(list.allocate(0), Some(0));
+        assert_eq!(list.allocate(1), None);
+    }
+
+    #[test]
+    fn free_merges_with_neighbours() {
+        let mut list = free_list(&[0..2, 6..8]);
+        // with the left neighbour
+        list.free(2..3);
+        assert_eq!(list.0, [0..3, 6..8]);
+        // with the right neighbour
+        list.free(5..6);
+        assert_eq!(list.0, [0..3, 5..8]);
+        // with both
+        list.free(3..5);
+        assert_eq!(list.0.len(), 1);
+        assert_eq!(list.0[0], 0..8);
+        // with neither, kept sorted
+        list.free(10..12);
+        list.free(9..9);
+        assert_eq!(list.0, [0..8, 10..12]);
+    }
+
+    #[test]
+    fn compacted_packs_in_current_order() {
+        let allocation = |vertices: Range<usize>, indices: Range<usize>| Some(Allocation { vertices, indices });
+        let slots = [
+            allocation(10..14, 0..6),
+            None,
+            allocation(2..5, 20..23),
+            allocation(20..21, 6..9),
+        ];
+        // ordered by where they start, not by slot
+        assert_eq!(compacted(&slots, |a| &a.vertices), [(2, 2..5, 0..3), (0, 10..14, 3..7), (3, 20..21, 7..8)]);
+        // already packed ranges stay where they are
+        assert_eq!(compacted(&slots, |a| &a.indices), [(0, 0..6, 0..6), (3, 6..9, 6..9), (2, 20..23, 9..12)]);
+        assert_eq!(compacted(&[None, None], |a| &a.vertices), []);
+    }
+}
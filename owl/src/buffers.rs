@@ -1,36 +1,465 @@
 use crate::ox;
-use crate::traits::ToByteVec;
-use crate::{OwlError, ToOwlError};
+use std::ops::Range;
+use std::rc::Rc;
+use crate::traits::{ToByteVec, FromByteVec, IndexElement, Pod, Std140, Std430};
+use crate::{OwlError, ToOwlError, MapFlags, MappedBuffer, MappedBufferMut, IndirectCommand};
 pub use ox::BufferUsage;
+pub use ox::BufferStorageFlags;
+
+/// The name / id of a buffer, deleted when the last of its owners is dropped: the wrapper of its data,
+/// and any vertex arrays reading from it, so that it outlives them all.
+///
+/// Whether it is mapped is shared with those owners too, as a vertex array cannot borrow the buffer,
+/// so must check it before drawing.
+#[derive(Debug)]
+pub(crate) struct BufferName {
+    name: ox::Buffer,
+    /// Whether the buffer is mapped through a [`MappedBuffer`] or [`MappedBufferMut`]
+    mapped: std::cell::Cell<bool>,
+}
+impl BufferName {
+    fn generate() -> Rc<Self> {
+        Rc::new(Self { name: ox::gen_buffer(), mapped: std::cell::Cell::new(false) })
+    }
+    pub(crate) const fn is_mapped(&self) -> bool {
+        self.mapped.get()
+    }
+    pub(crate) fn set_mapped(&self, mapped: bool) {
+        self.mapped.set(mapped);
+    }
+}
+impl std::ops::Deref for BufferName {
+    type Target = ox::Buffer;
+    fn deref(&self) -> &ox::Buffer {
+        &self.name
+    }
+}
+impl Drop for BufferName {
+    fn drop(&mut self) {
+        ox::delete_buffer(&self.name);
+    }
+}
 
 /// A struct to couple the name / id of a buffer with ownership of its data.
-/// For now, it does not actually contain the data stored in OpenGL memory
-#[derive(Clone, Debug)]
-struct Buffer<T: ToByteVec> {
-    id: ox::Buffer,
+/// It does not actually contain the data stored in OpenGL memory, but keeps track of how much there is
+/// It is not Clone, as a clone would share its data: see [`Buffer::duplicate`].
+#[derive(Debug)]
+struct Buffer<T> {
+    id: Rc<BufferName>,
+    /// The number of bytes per element
+    stride: usize,
+    /// The number of elements stored
+    length: usize,
+    /// The number of elements there is space for
+    capacity: usize,
+    storage: Storage,
+    persistent: Option<PersistentMapping>,
     _ghost: std::marker::PhantomData<T>
 }
-impl<T: ToByteVec> Buffer<T> {
-    fn new() -> Self {
+/// How the data store of a buffer was allocated
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Storage {
+    /// with `glBufferData`, so it can always be updated, or reallocated with the same usage
+    Mutable(BufferUsage),
+    /// with `glBufferStorage`, so it can only be updated as the flags allow
+    Immutable(BufferStorageFlags),
+}
+/// A mapping of the whole buffer, which lasts as long as it does:
+/// deleting the buffer unmaps it implicitly
+#[derive(Clone, Copy, Debug)]
+struct PersistentMapping {
+    pointer: std::ptr::NonNull<u8>,
+    length: usize,
+    /// Whether it was mapped with `GL_MAP_READ_BIT`
+    readable: bool,
+    /// Whether it was mapped with `GL_MAP_WRITE_BIT`
+    writable: bool,
+    coherent: bool,
+}
+impl<T> Buffer<T> {
+    /// A buffer of `length` elements, which will be allocated with `glBufferData`
+    fn new(stride: usize, length: usize, usage: BufferUsage) -> Self {
         Self {
-            id: ox::gen_buffer(),
+            id: BufferName::generate(),
+            stride,
+            length,
+            capacity: length,
+            storage: Storage::Mutable(usage),
+            persistent: None,
+            _ghost: std::marker::PhantomData,
+        }
+    }
+    /// Allocate immutable storage for the buffer, already bound to `target`, from `data` as laid out
+    /// in memory (either the elements themselves, or their bytes), and map it
+    /// if the flags request a persistent mapping
+    ///
+    /// # Errors
+    /// This function will return an error if the data is empty, the flags are invalid,
+    /// or we are out of memory.
+    fn allocate_immutable<B: Pod>(&mut self, target: ox::BufferType, data: &[B], flags: BufferStorageFlags)
+        -> Result<(),OwlError> {
+        let allocated = ox::buffer_storage(target, data, flags);
+        self.finish_immutable(target, flags, allocated)
+    }
+    /// Allocate immutable storage of `size` bytes, with undefined contents, for the buffer already bound to
+    /// `target`, and map it if the flags request a persistent mapping
+    ///
+    /// # Errors
+    /// This function will return an error if the size is 0, the flags are invalid,
+    /// or we are out of memory.
+    fn allocate_immutable_uninitialised(&mut self, target: ox::BufferType, size: usize, flags: BufferStorageFlags)
+        -> Result<(),OwlError> {
+        let allocated = ox::buffer_storage_uninitialised(target, size, flags);
+        self.finish_immutable(target, flags, allocated)
+    }
+    /// Record the result of allocating immutable storage, mapping it if the flags request a persistent mapping
+    ///
+    /// # Errors
+    /// This function will return an error if the allocation failed.
+    fn finish_immutable(&mut self, target: ox::BufferType, flags: BufferStorageFlags,
+        allocated: Result<(),ox::OxError>) -> Result<(),OwlError> {
+        allocated.map_err(|e| {
+            match e {
+                ox::OxError::BaseError(crate::OriginalError::InvalidValue) => {
+                    e.with_message("either the storage is empty, or MapPersistent was used without MapRead or MapWrite, \
+                        or MapCoherent without MapPersistent")
+                },
+                ox::OxError::BaseError(crate::OriginalError::OutOfMemory) => {
+                    e.with_message("out of memory")
+                },
+                _ => e.with_message("no other errors should be produced")
+            }
+        })?;
+        self.storage = Storage::Immutable(flags);
+        self.capacity = self.length;
+        if flags.contains(BufferStorageFlags::MapPersistent) {
+            let length = ox::get_buffer_size(target)
+                .expect("buffer is bound");
+            let mut access = ox::MapAccessFlags::Persistent;
+            let readable = flags.contains(BufferStorageFlags::MapRead);
+            if readable {
+                access |= ox::MapAccessFlags::Read;
+            }
+            let writable = flags.contains(BufferStorageFlags::MapWrite);
+            if writable {
+                access |= ox::MapAccessFlags::Write;
+            }
+            let coherent = flags.contains(BufferStorageFlags::MapCoherent);
+            if coherent {
+                access |= ox::MapAccessFlags::Coherent;
+            } else if writable {
+                // writes must be flushed by hand, see [`Buffer::flush_persistent`]
+                access |= ox::MapAccessFlags::FlushExplicit;
+            }
+            let pointer = ox::map_buffer_range(target, 0, length, access)
+                .map_err(|e| e.with_message("out of memory"))?;
+            self.persistent = Some(PersistentMapping { pointer, length, readable, writable, coherent });
+        }
+        Ok(())
+    }
+    /// A new buffer with the same storage, and a copy of the contents, made without a round trip through
+    /// the CPU. Any persistent mapping is recreated for the new buffer.
+    ///
+    /// # Errors
+    /// This function will return an error if we are out of memory, or the buffer is being mapped
+    /// (other than persistently).
+    fn duplicate(&self) -> Result<Self, OwlError> {
+        let mut duplicate = Self {
+            id: BufferName::generate(),
+            persistent: None,
             _ghost: std::marker::PhantomData,
+            ..*self
+        };
+        // the size is queried, as some wrappers store more than their elements
+        ox::bind_buffer(ox::BufferType::CopyRead, Some(&self.id))
+            .expect("buffer should not be deleted yet");
+        let size = ox::get_buffer_size(ox::BufferType::CopyRead)
+            .expect("buffer was just bound");
+        let duplicated = (|| {
+            ox::bind_buffer(ox::BufferType::CopyWrite, Some(&duplicate.id))
+                .expect("buffer was just generated");
+            match self.storage {
+                Storage::Mutable(usage) => ox::buffer_data_uninitialised(ox::BufferType::CopyWrite, size, usage)
+                    .with_message("out of memory")?,
+                Storage::Immutable(flags) =>
+                    duplicate.allocate_immutable_uninitialised(ox::BufferType::CopyWrite, size, flags)?,
+            }
+            if size > 0 {
+                copy_bytes(&self.id, &duplicate.id, 0, 0, size)?;
+            }
+            Ok(())
+        })();
+        // on failure, the duplicate's name is deleted as it is dropped
+        duplicated.map(|()| duplicate)
+    }
+    /// Reallocate the buffer with space for `capacity` elements, keeping its contents and id,
+    /// so that vertex arrays using it need not be updated
+    ///
+    /// # Errors
+    /// This function will return an error if the buffer's storage is immutable, or we are out of memory.
+    fn reallocate(&mut self, capacity: usize) -> Result<(),OwlError> {
+        let Storage::Mutable(usage) = self.storage else {
+            return Err(OwlError::custom("buffer has immutable storage, which cannot be resized"));
+        };
+        let kept = self.length.min(capacity) * self.stride;
+        // the contents are kept in a temporary buffer while the original is reallocated
+        let temporary = ox::gen_buffer();
+        let reallocated = (|| {
+            if kept > 0 {
+                ox::bind_buffer(ox::BufferType::CopyWrite, Some(&temporary))
+                    .expect("buffer was just generated");
+                ox::buffer_data_uninitialised(ox::BufferType::CopyWrite, kept, BufferUsage::StreamCopy)
+                    .with_message("out of memory")?;
+                copy_bytes(&self.id, &temporary, 0, 0, kept)?;
+            }
+            ox::bind_buffer(ox::BufferType::CopyWrite, Some(&self.id))
+                .expect("buffer should not be deleted yet");
+            ox::buffer_data_uninitialised(ox::BufferType::CopyWrite, capacity * self.stride, usage)
+                .with_message("out of memory")?;
+            if kept > 0 {
+                copy_bytes(&temporary, &self.id, 0, 0, kept)?;
+            }
+            Ok(())
+        })();
+        ox::delete_buffer(&temporary);
+        reallocated?;
+        self.capacity = capacity;
+        self.length = self.length.min(capacity);
+        Ok(())
+    }
+    /// Append `data` to the buffer, which is bound to `target`, reallocating it if it is full
+    ///
+    /// # Errors
+    /// This function will return an error if the buffer needs reallocating, but its storage is
+    /// immutable, if we are out of memory, or if the buffer is being mapped.
+    fn extend(&mut self, target: ox::BufferType, data: Vec<T>) -> Result<(),OwlError> where T: ToByteVec {
+        if self.stride == 0 {
+            self.stride = stride_of(&data);
+        }
+        let new_length = self.length + data.len();
+        self.reserve_for(data.len())?;
+        ox::bind_buffer(target, Some(&self.id))
+            .expect("buffer should not be deleted yet");
+        ox::buffer_subdata(target, data, self.length).map_err(|e| {
+            match e {
+                ox::OxError::BaseError(crate::OriginalError::InvalidOperation) => {
+                    e.with_message("buffer is being mapped")
+                },
+                _ => e.with_message("no other errors should be produced")
+            }
+        })?;
+        self.length = new_length;
+        Ok(())
+    }
+    /// Append `data` to the buffer, which is bound to `target`, without copying it first
+    ///
+    /// # Errors
+    /// As [`Buffer::extend`]
+    fn extend_from_slice(&mut self, target: ox::BufferType, data: &[T]) -> Result<(),OwlError> where T: Pod {
+        if self.stride == 0 {
+            self.stride = std::mem::size_of::<T>();
+        }
+        self.reserve_for(data.len())?;
+        ox::bind_buffer(target, Some(&self.id))
+            .expect("buffer should not be deleted yet");
+        ox::buffer_subdata_slice(target, data, self.length).map_err(|e| {
+            match e {
+                ox::OxError::BaseError(crate::OriginalError::InvalidOperation) => {
+                    e.with_message("buffer is being mapped")
+                },
+                _ => e.with_message("no other errors should be produced")
+            }
+        })?;
+        self.length += data.len();
+        Ok(())
+    }
+    /// Reallocate the buffer if there is not space for `additional` elements
+    ///
+    /// # Errors
+    /// As [`Buffer::reallocate`]
+    fn reserve_for(&mut self, additional: usize) -> Result<(),OwlError> {
+        let new_length = self.length + additional;
+        if new_length > self.capacity {
+            // grow geometrically, as a Vec does, so that repeated extends are cheap
+            self.reallocate(new_length.max(self.capacity * 2))?;
+        }
+        Ok(())
+    }
+    /// Copy `length` elements of `source`, starting from `source_offset`, into this buffer starting
+    /// from `destination_offset`, on the GPU. Unlike updates, copies may write immutable storage
+    /// created without [`BufferStorageFlags::DynamicStorage`].
+    ///
+    /// # Errors
+    /// This function will return an error if either range overflows its buffer, the elements
+    /// of each buffer have different strides, or either buffer is being mapped.
+    fn copy_from(&self, source: &Self, source_offset: usize, destination_offset: usize,
+        length: usize) -> Result<(),OwlError> {
+        source.check_bounds(source_offset, length).with_context("copying from the source buffer")?;
+        self.check_bounds(destination_offset, length)?;
+        if self.stride != 0 && source.stride != 0 && self.stride != source.stride {
+            return Err(OwlError::custom("element strides differ"));
+        }
+        let stride = self.stride.max(source.stride);
+        copy_bytes(&source.id, &self.id, source_offset * stride,
+            destination_offset * stride, length * stride)
+    }
+    /// Copy `length` elements, starting from `source_offset`, to `destination_offset` within this
+    /// buffer, on the GPU
+    ///
+    /// # Errors
+    /// This function will return an error if either range overflows the buffer, the ranges
+    /// overlap, or the buffer is being mapped.
+    fn copy_within(&self, source_offset: usize, destination_offset: usize,
+        length: usize) -> Result<(),OwlError> {
+        self.check_bounds(source_offset, length)?;
+        self.check_bounds(destination_offset, length)?;
+        copy_bytes(&self.id, &self.id, source_offset * self.stride,
+            destination_offset * self.stride, length * self.stride)
+    }
+    /// # Errors
+    /// This function will return an error if `offset` + `length` elements exceed the buffer's length.
+    fn check_bounds(&self, offset: usize, length: usize) -> Result<(),OwlError> {
+        if offset + length > self.length {
+            Err(OwlError::custom("offset + length > buffer length"))
+        } else {
+            Ok(())
+        }
+    }
+    /// # Errors
+    /// This function will return an error if the buffer's storage is immutable, and not dynamic.
+    fn check_updatable(&self) -> Result<(),OwlError> {
+        match self.storage {
+            Storage::Immutable(flags) if !flags.contains(BufferStorageFlags::DynamicStorage) => {
+                Err(OwlError::custom("buffer has immutable storage, created without DynamicStorage"))
+            },
+            _ => Ok(())
+        }
+    }
+    /// Make writes through the persistent mapping to the elements in `range` visible to OpenGL,
+    /// for a buffer already bound to `target`
+    ///
+    /// # Errors
+    /// This function will return an error if the buffer is not persistently mapped for writing,
+    /// or the range exceeds it.
+    fn flush_persistent(&self, target: ox::BufferType, range: Range<usize>) -> Result<(),OwlError> {
+        let Some(mapping) = self.persistent.filter(|mapping| mapping.writable) else {
+            return Err(OwlError::custom("buffer is not persistently mapped for writing"));
+        };
+        let element_size = std::mem::size_of::<T>();
+        if range.start > range.end || range.end * element_size > mapping.length {
+            return Err(OwlError::custom("range exceeds buffer size"));
         }
+        if mapping.coherent {
+            // coherent writes are visible without flushing
+            return Ok(());
+        }
+        ox::flush_mapped_buffer_range(target, range.start * element_size, range.len() * element_size)
+            .map_err(|e| {
+                match e {
+                    ox::OxError::BaseError(crate::OriginalError::InvalidOperation) => {
+                        e.with_message("buffer was not mapped for writing")
+                    },
+                    _ => e.with_message("no other errors should be produced")
+                }
+            })
+    }
+}
+impl<T: Pod> Buffer<T> {
+    /// The persistent mapping, if it may be read
+    fn persistent(&self) -> Option<&[T]> {
+        self.persistent.filter(|mapping| mapping.readable).map(|mapping| {
+            // SAFETY: the mapping is valid for `length` bytes until the buffer is deleted, on drop,
+            // and aligned to GL_MIN_MAP_BUFFER_ALIGNMENT (at least 64).
+            // Any bit pattern is a valid T, as it is Pod.
+            unsafe {
+                std::slice::from_raw_parts(mapping.pointer.as_ptr().cast::<T>(),
+                    mapping.length / std::mem::size_of::<T>().max(1))
+            }
+        })
+    }
+    #[allow(clippy::needless_pass_by_ref_mut)]     // the borrow guards exclusive access to the mapping
+    /// The persistent mapping, if it may be written
+    fn persistent_mut(&mut self) -> Option<&mut [T]> {
+        self.persistent.filter(|mapping| mapping.writable).map(|mapping| {
+            // SAFETY: as in persistent; it was mapped for writing, and the buffer is mutably
+            // borrowed, so this is the only reference to the mapping
+            unsafe {
+                std::slice::from_raw_parts_mut(mapping.pointer.as_ptr().cast::<T>(),
+                    mapping.length / std::mem::size_of::<T>().max(1))
+            }
+        })
     }
 }
-impl<T: ToByteVec> Default for Buffer<T> {
+impl<T> Default for Buffer<T> {
     fn default() -> Self {
-        Self::new()
+        Self::new(0, 0, BufferUsage::StaticDraw)
     }
 }
-impl<T: ToByteVec> PartialEq for Buffer<T> {
+impl<T> PartialEq for Buffer<T> {
     fn eq(&self, other: &Self) -> bool {
-        self.id == other.id
+        Rc::ptr_eq(&self.id, &other.id)
+    }
+}
+impl<T> Eq for Buffer<T> {}
+/// The number of bytes per element of `data`, known without any elements if it does not depend on
+/// their values, otherwise 0 if it is empty
+fn stride_of<T: ToByteVec>(data: &[T]) -> usize {
+    T::STRIDE.map_or_else(|| data.first().map_or(0, |datum| datum.stride().0), usize::from)
+}
+/// Read `length` bytes from `offset` in `buffer` back to client memory
+///
+/// # Errors
+/// This function will return an error if the range exceeds the buffer, or it is being mapped.
+fn read_bytes(buffer: &ox::Buffer, offset: usize, length: usize) -> Result<Vec<u8>,OwlError> {
+    // reading through COPY_READ leaves the bindings that affect drawing alone
+    ox::bind_buffer(ox::BufferType::CopyRead, Some(buffer))
+        .expect("buffer should not be deleted yet");
+    if offset + length > ox::get_buffer_size(ox::BufferType::CopyRead).expect("buffer was just bound") {
+        return Err(OwlError::custom("offset + length > buffer size"));
+    }
+    ox::get_buffer_sub_data(ox::BufferType::CopyRead, offset, length).map_err(|e| {
+        match e {
+            ox::OxError::BaseError(crate::OriginalError::InvalidOperation) => {
+                e.with_message("buffer is being mapped")
+            },
+            _ => e.with_message("no other errors should be produced")
+        }
+    })
+}
+/// Copy `length` bytes from `source_offset` in `source`, to `destination_offset` in `destination`,
+/// without a round trip through client memory
+///
+/// # Errors
+/// This function will return an error if either range exceeds its buffer, the ranges overlap
+/// within the same buffer, or either buffer is being mapped.
+fn copy_bytes(source: &ox::Buffer, destination: &ox::Buffer, source_offset: usize,
+    destination_offset: usize, length: usize) -> Result<(),OwlError> {
+    ox::bind_buffer(ox::BufferType::CopyRead, Some(source))
+        .expect("buffer should not be deleted yet");
+    ox::bind_buffer(ox::BufferType::CopyWrite, Some(destination))
+        .expect("buffer should not be deleted yet");
+    if source_offset + length > ox::get_buffer_size(ox::BufferType::CopyRead).expect("buffer was just bound") {
+        return Err(OwlError::custom("source offset + length > source buffer size"));
     }
+    if destination_offset + length > ox::get_buffer_size(ox::BufferType::CopyWrite).expect("buffer was just bound") {
+        return Err(OwlError::custom("destination offset + length > destination buffer size"));
+    }
+    if source == destination && source_offset < destination_offset + length
+        && destination_offset < source_offset + length {
+        return Err(OwlError::custom("source and destination ranges overlap"));
+    }
+    ox::copy_buffer_sub_data(ox::BufferType::CopyRead, ox::BufferType::CopyWrite,
+        source_offset, destination_offset, length).map_err(|e| {
+        match e {
+            ox::OxError::BaseError(crate::OriginalError::InvalidOperation) => {
+                e.with_message("one of the buffers is being mapped")
+            },
+            _ => e.with_message("no other errors should be produced")
+        }
+    })
 }
-impl<T: ToByteVec> Eq for Buffer<T> {}
 /// A wrapper around [Buffer], that allows functions using it to specify the `ARRAY_BUFFER` target
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq)]
 pub struct ArrayBuffer<T: ToByteVec>(Buffer<T>);
 // INVARIANT: will not be deleted until it is dropped
 // fewer calls can fail, reducing error handling, but they now "expect"
@@ -44,28 +473,43 @@ impl<T: ToByteVec> ArrayBuffer<T> {
     /// of a more ergonomic API
     pub fn new(data: Vec<T>, usage: BufferUsage) -> Result<Self, OwlError> 
         where T: ToByteVec {
-        let created = Self(Buffer::new());
+        let created = Self(Buffer::new(stride_of(&data), data.len(), usage));
         created.bind();
         // buffer cannot be immutable, so must be out of memory
         ox::buffer_data(ox::BufferType::Array, data, usage)
             .with_context("creating ArrayBuffer")?;
         Ok(created)
     }
+    /// Create a buffer with immutable storage: it cannot be resized, and can only be updated with
+    /// [`BufferStorageFlags::DynamicStorage`]. With [`BufferStorageFlags::MapPersistent`],
+    /// it is mapped for as long as it exists, see [`ArrayBuffer::persistent`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the data is empty, we are out of memory,
+    /// or the flags are invalid: `MapPersistent` requires `MapRead` or `MapWrite`,
+    /// and `MapCoherent` requires `MapPersistent`.
+    pub fn new_immutable(data: Vec<T>, flags: BufferStorageFlags) -> Result<Self, OwlError> {
+        let mut created = Self(Buffer::new(stride_of(&data), data.len(), BufferUsage::StaticDraw));
+        created.bind();
+        created.0.allocate_immutable(ox::BufferType::Array, &data.to_byte_vec(), flags)
+            .with_context("creating immutable ArrayBuffer")?;
+        Ok(created)
+    }
     // TODO: error handling... again.
 
     /// # Errors
     ///
     /// This function will return an error if the size of the data at the given offset overflows
-    /// the buffer, or if the buffer is being mapped. At the moment, I have no clue what the
-    /// latter means.
+    /// the buffer, if the buffer is being mapped, or if it has immutable storage created without
+    /// [`BufferStorageFlags::DynamicStorage`].
     pub fn update(&mut self, data: Vec<T>, offset: usize) -> Result<(),OwlError> {
+        self.0.check_updatable().with_context("updating ArrayBuffer")?;
+        self.0.check_bounds(offset, data.len()).with_context("updating ArrayBuffer")?;
         self.bind();
         ox::buffer_subdata(ox::BufferType::Array, data, offset).map_err(|e| {
             match e {
                 ox::OxError::BaseError(crate::OriginalError::InvalidOperation) => {
-                    e.with_message("offset + data length > buffer size")
-                },
-                ox::OxError::BaseError(crate::OriginalError::InvalidValue) => {
                     e.with_message("buffer is being mapped")
                 },
                 _ => e.with_message("no other errors should be produced")
@@ -73,9 +517,101 @@ impl<T: ToByteVec> ArrayBuffer<T> {
             .with_context("updating ArrayBuffer")
         })
     }
+    /// Copy `length` elements of `source`, starting from `source_offset`, into this buffer
+    /// starting from `destination_offset`, without a round trip through the CPU
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if either range overflows its buffer, the elements
+    /// of each buffer have different strides, or either buffer is being mapped.
+    pub fn copy_from(&mut self, source: &Self, source_offset: usize, destination_offset: usize,
+        length: usize) -> Result<(),OwlError> {
+        self.0.copy_from(&source.0, source_offset, destination_offset, length)
+            .with_context("copying to ArrayBuffer")
+    }
+    /// Copy `length` elements, starting from `source_offset`, to `destination_offset`
+    /// within this buffer, e.g. to compact it
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if either range overflows the buffer, the ranges
+    /// overlap, or the buffer is being mapped.
+    pub fn copy_within(&mut self, source_offset: usize, destination_offset: usize,
+        length: usize) -> Result<(),OwlError> {
+        self.0.copy_within(source_offset, destination_offset, length)
+            .with_context("copying within ArrayBuffer")
+    }
+    /// A new buffer with a copy of this one's contents and storage, made without a round trip through
+    /// the CPU. `ArrayBuffer` is not Clone, as both copies would delete the same buffer when dropped.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if we are out of memory.
+    pub fn duplicate(&self) -> Result<Self, OwlError> {
+        self.0.duplicate().map(Self).with_context("duplicating ArrayBuffer")
+    }
+    /// The number of elements stored
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.0.length
+    }
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.0.length == 0
+    }
+    /// The number of elements there is space for, before the buffer must be reallocated
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        self.0.capacity
+    }
+    /// Append `data` to the buffer, reallocating it (while keeping its contents) if it is full
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the buffer must be reallocated, but has immutable storage,
+    /// if we are out of memory, or if the buffer is being mapped.
+    pub fn extend(&mut self, data: Vec<T>) -> Result<(),OwlError> {
+        self.0.check_updatable().with_context("extending ArrayBuffer")?;
+        self.0.extend(ox::BufferType::Array, data)
+            .with_context("extending ArrayBuffer")
+    }
+    /// Resize the buffer to hold `length` elements, either truncating it, or filling the new elements
+    /// with `value`. Truncating keeps the capacity, extending may reallocate.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the buffer must be reallocated, but has immutable storage,
+    /// if we are out of memory, or if the buffer is being mapped.
+    pub fn resize(&mut self, length: usize, value: T) -> Result<(),OwlError> where T: Clone {
+        if length <= self.0.length {
+            self.0.length = length;
+            Ok(())
+        } else {
+            self.extend(vec![value; length - self.0.length])
+                .with_context("resizing ArrayBuffer")
+        }
+    }
+    /// Reallocate the buffer to make space for at least `additional` more elements,
+    /// keeping its contents
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the buffer has immutable storage,
+    /// or we are out of memory.
+    pub fn reserve(&mut self, additional: usize) -> Result<(),OwlError> {
+        if self.0.length + additional > self.0.capacity {
+            self.0.reallocate(self.0.length + additional)
+                .with_context("reserving space in ArrayBuffer")?;
+        }
+        Ok(())
+    }
+    /// A handle to the buffer's name, which keeps it from being deleted while it is held
+    pub(crate) fn shared_name(&self) -> Rc<BufferName> {
+        Rc::clone(&self.0.id)
+    }
     // let's see if we can't limit the scope to crate.
     pub(crate) fn bind(&self) {
-        ox::bind_buffer(ox::BufferType::Array, Some(self.0.id))
+        ox::bind_buffer(ox::BufferType::Array, Some(&self.0.id))
             .expect("buffer should not be deleted yet");
     }
     pub(crate) fn unbind() {
@@ -83,49 +619,192 @@ impl<T: ToByteVec> ArrayBuffer<T> {
             .expect("binding 0 always succeeds");
     }
 }
-impl<T: ToByteVec> Drop for ArrayBuffer<T> {
-    fn drop(&mut self) {
-        ox::delete_buffer(self.0.id);
+impl<T: Pod> ArrayBuffer<T> {
+    /// Create a buffer from `data` as it is laid out in memory, without copying it first
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if we are out of memory.
+    pub fn from_slice(data: &[T], usage: BufferUsage) -> Result<Self, OwlError> {
+        let created = Self(Buffer::new(std::mem::size_of::<T>(), data.len(), usage));
+        created.bind();
+        ox::buffer_data_slice(ox::BufferType::Array, data, usage)
+            .with_context("creating ArrayBuffer")?;
+        Ok(created)
+    }
+    /// As [`ArrayBuffer::new_immutable`], from `data` as it is laid out in memory,
+    /// without copying it first
+    ///
+    /// # Errors
+    ///
+    /// As [`ArrayBuffer::new_immutable`]
+    pub fn immutable_from_slice(data: &[T], flags: BufferStorageFlags) -> Result<Self, OwlError> {
+        let mut created = Self(Buffer::new(std::mem::size_of::<T>(), data.len(), BufferUsage::StaticDraw));
+        created.bind();
+        created.0.allocate_immutable(ox::BufferType::Array, data, flags)
+            .with_context("creating immutable ArrayBuffer")?;
+        Ok(created)
+    }
+    /// As [`ArrayBuffer::update`], from `data` as it is laid out in memory, without copying it first
+    ///
+    /// # Errors
+    ///
+    /// As [`ArrayBuffer::update`]
+    pub fn update_from_slice(&mut self, data: &[T], offset: usize) -> Result<(),OwlError> {
+        self.0.check_updatable().with_context("updating ArrayBuffer")?;
+        self.0.check_bounds(offset, data.len()).with_context("updating ArrayBuffer")?;
+        self.bind();
+        ox::buffer_subdata_slice(ox::BufferType::Array, data, offset).map_err(|e| {
+            match e {
+                ox::OxError::BaseError(crate::OriginalError::InvalidOperation) => {
+                    e.with_message("buffer is being mapped")
+                },
+                _ => e.with_message("no other errors should be produced")
+            }
+            .with_context("updating ArrayBuffer")
+        })
+    }
+    /// As [`ArrayBuffer::extend`], from `data` as it is laid out in memory, without copying it first
+    ///
+    /// # Errors
+    ///
+    /// As [`ArrayBuffer::extend`]
+    pub fn extend_from_slice(&mut self, data: &[T]) -> Result<(),OwlError> {
+        self.0.check_updatable().with_context("extending ArrayBuffer")?;
+        self.0.extend_from_slice(ox::BufferType::Array, data)
+            .with_context("extending ArrayBuffer")
+    }
+    /// Map the whole buffer, to be read through the returned guard
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the buffer is empty.
+    pub fn map_read(&mut self) -> Result<MappedBuffer<'_, T>, OwlError> {
+        MappedBuffer::new(ox::BufferType::Array, &self.0.id, 0..self.0.length)
+            .with_context("mapping ArrayBuffer for reading")
+    }
+    /// Map the whole buffer, to be written through the returned guard
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the buffer is empty.
+    pub fn map_write(&mut self) -> Result<MappedBufferMut<'_, T>, OwlError> {
+        MappedBufferMut::new(ox::BufferType::Array, &self.0.id, 0..self.0.length, MapFlags::empty())
+            .with_context("mapping ArrayBuffer for writing")
+    }
+    /// Map the elements in `range`, to be written (and, with [`MapFlags::Read`], read)
+    /// through the returned guard
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the range is empty or exceeds the buffer,
+    /// or [`MapFlags::Read`] is combined with any other flag.
+    pub fn map_range(&mut self, range: Range<usize>, flags: MapFlags) -> Result<MappedBufferMut<'_, T>, OwlError> {
+        self.0.check_bounds(range.start, range.len()).with_context("mapping range of ArrayBuffer")?;
+        MappedBufferMut::new(ox::BufferType::Array, &self.0.id, range, flags)
+            .with_context("mapping range of ArrayBuffer")
+    }
+    /// The contents of the buffer, if it was created with [`BufferStorageFlags::MapPersistent`]
+    /// and [`BufferStorageFlags::MapRead`]
+    #[must_use]
+    pub fn persistent(&self) -> Option<&[T]> {
+        self.0.persistent()
+    }
+    /// The contents of the buffer, if it was created with [`BufferStorageFlags::MapPersistent`]
+    /// and [`BufferStorageFlags::MapWrite`]. Without [`BufferStorageFlags::MapCoherent`], writes
+    /// must be made visible with [`ArrayBuffer::flush_persistent`].
+    ///
+    /// # Notes
+    /// Unless it was also created with [`BufferStorageFlags::MapRead`], reading from it is allowed,
+    /// but its contents are undefined, and likely to be slow to access.
+    pub fn persistent_mut(&mut self) -> Option<&mut [T]> {
+        self.0.persistent_mut()
+    }
+    /// Make writes through [`ArrayBuffer::persistent_mut`] to the elements in `range` visible to OpenGL;
+    /// unnecessary, though harmless, for coherent buffers.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the buffer is not persistently mapped for writing,
+    /// or the range exceeds it.
+    pub fn flush_persistent(&self, range: Range<usize>) -> Result<(),OwlError> {
+        self.bind();
+        self.0.flush_persistent(ox::BufferType::Array, range)
+            .with_context("flushing ArrayBuffer")
+    }
+}
+impl<T: FromByteVec> ArrayBuffer<T> {
+    /// Read the elements in `range` back from the buffer
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the range exceeds the buffer,
+    /// or the buffer is being mapped.
+    pub fn read(&self, range: Range<usize>) -> Result<Vec<T>, OwlError> {
+        if range.start > range.end {
+            return Err(OwlError::custom("range start > range end"))
+                .with_context("reading ArrayBuffer");
+        }
+        self.0.check_bounds(range.start, range.len()).with_context("reading ArrayBuffer")?;
+        let bytes = read_bytes(&self.0.id, range.start * T::BYTE_SIZE, range.len() * T::BYTE_SIZE)
+            .with_context("reading ArrayBuffer")?;
+        Ok(T::from_byte_vec(&bytes))
     }
 }
-
 pub use ox::IndexType;
-/// A wrapper around [Buffer], that allows functions using it to specify the `ARRAY_BUFFER` target
-#[derive(Clone, Debug, PartialEq, Eq)]
-pub struct ElementBuffer<T: ToByteVec>{
+/// A wrapper around [Buffer], that allows functions using it to specify the `ELEMENT_ARRAY_BUFFER` target.
+///
+/// The index type used to draw with it is that of `T`, and indices are uploaded from slices as they are
+/// laid out in memory, without copying them first.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ElementBuffer<T: IndexElement>{
     inner: Buffer<T>,
-    pub(crate) inner_type: ox::IndexType
 }
-impl<T: ToByteVec> ElementBuffer<T> {
+impl<T: IndexElement> ElementBuffer<T> {
     // INVARIANT: buffer will not be deleted until it is dropped
     // fewer calls can fail, reducing error handling, but they now "expect"
     /// # Errors
     /// out of memory
-    pub fn new(data: Vec<T>, usage: BufferUsage, index_type: IndexType) -> Result<Self, OwlError> 
-        where T: ToByteVec {
+    pub fn new(data: &[T], usage: BufferUsage) -> Result<Self, OwlError> {
         let created = Self {
-            inner: Buffer::new(),
-            inner_type: index_type
+            inner: Buffer::new(stride_of(data), data.len(), usage),
         };
-        created.bind();
+        created.bind_data();
         // buffer cannot be immutable, so must be out of memory
-        ox::buffer_data(ox::BufferType::ElementArray, data, usage)
+        ox::buffer_data_slice(ox::BufferType::CopyWrite, data, usage)
             .with_context("creating ElementBuffer")?;
         Ok(created)
     }
+    /// Create a buffer with immutable storage: it cannot be resized, and can only be updated with
+    /// [`BufferStorageFlags::DynamicStorage`]. With [`BufferStorageFlags::MapPersistent`],
+    /// it is mapped for as long as it exists, see [`ElementBuffer::persistent`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the data is empty, we are out of memory,
+    /// or the flags are invalid: `MapPersistent` requires `MapRead` or `MapWrite`,
+    /// and `MapCoherent` requires `MapPersistent`.
+    pub fn new_immutable(data: &[T], flags: BufferStorageFlags) -> Result<Self, OwlError> {
+        let mut created = Self {
+            inner: Buffer::new(stride_of(data), data.len(), BufferUsage::StaticDraw),
+        };
+        created.bind_data();
+        created.inner.allocate_immutable(ox::BufferType::CopyWrite, data, flags)
+            .with_context("creating immutable ElementBuffer")?;
+        Ok(created)
+    }
     /// # Errors
     ///
     /// This function will return an error if the size of the data at the given offset overflows
-    /// the buffer, or if the buffer is being mapped. At the moment, I have no clue what the
-    /// latter means.
-    pub fn update(&mut self, data: Vec<T>, offset: usize) -> Result<(),OwlError> {
-        self.bind();
-        ox::buffer_subdata(ox::BufferType::ElementArray, data, offset).map_err(|e| {
+    /// the buffer, if the buffer is being mapped, or if it has immutable storage created without
+    /// [`BufferStorageFlags::DynamicStorage`].
+    pub fn update(&mut self, data: &[T], offset: usize) -> Result<(),OwlError> {
+        self.inner.check_updatable().with_context("updating ElementBuffer")?;
+        self.inner.check_bounds(offset, data.len()).with_context("updating ElementBuffer")?;
+        self.bind_data();
+        ox::buffer_subdata_slice(ox::BufferType::CopyWrite, data, offset).map_err(|e| {
             match e {
                 ox::OxError::BaseError(crate::OriginalError::InvalidOperation) => {
-                    e.with_message("offset + data length > buffer size")
-                },
-                ox::OxError::BaseError(crate::OriginalError::InvalidValue) => {
                     e.with_message("buffer is being mapped")
                 },
                 _ => e.with_message("no other errors should be produced")
@@ -133,17 +812,985 @@ impl<T: ToByteVec> ElementBuffer<T> {
             .with_context("updating ElementBuffer")
         })
     }
-    /// let's see if we can't limit the scope to crate.
+    /// Copy `length` elements of `source`, starting from `source_offset`, into this buffer
+    /// starting from `destination_offset`, without a round trip through the CPU
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if either range overflows its buffer, the elements
+    /// of each buffer have different strides, or either buffer is being mapped.
+    pub fn copy_from(&mut self, source: &Self, source_offset: usize, destination_offset: usize,
+        length: usize) -> Result<(),OwlError> {
+        self.inner.copy_from(&source.inner, source_offset, destination_offset, length)
+            .with_context("copying to ElementBuffer")
+    }
+    /// Copy `length` elements, starting from `source_offset`, to `destination_offset`
+    /// within this buffer, e.g. to compact it
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if either range overflows the buffer, the ranges
+    /// overlap, or the buffer is being mapped.
+    pub fn copy_within(&mut self, source_offset: usize, destination_offset: usize,
+        length: usize) -> Result<(),OwlError> {
+        self.inner.copy_within(source_offset, destination_offset, length)
+            .with_context("copying within ElementBuffer")
+    }
+    /// A new buffer with a copy of the indices, made on the GPU
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if we are out of memory.
+    pub fn duplicate(&self) -> Result<Self, OwlError> {
+        self.inner.duplicate().map(|inner| Self { inner }).with_context("duplicating ElementBuffer")
+    }
+    /// The number of elements stored
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.inner.length
+    }
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.inner.length == 0
+    }
+    /// The number of elements there is space for, before the buffer must be reallocated
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        self.inner.capacity
+    }
+    /// Append `data` to the buffer, reallocating it (while keeping its contents) if it is full
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the buffer must be reallocated, but has immutable storage,
+    /// if we are out of memory, or if the buffer is being mapped.
+    pub fn extend(&mut self, data: &[T]) -> Result<(),OwlError> {
+        self.inner.check_updatable().with_context("extending ElementBuffer")?;
+        self.inner.extend_from_slice(ox::BufferType::CopyWrite, data)
+            .with_context("extending ElementBuffer")
+    }
+    /// Resize the buffer to hold `length` elements, either truncating it, or filling the new elements
+    /// with `value`. Truncating keeps the capacity, extending may reallocate.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the buffer must be reallocated, but has immutable storage,
+    /// if we are out of memory, or if the buffer is being mapped.
+    pub fn resize(&mut self, length: usize, value: T) -> Result<(),OwlError> where T: Clone {
+        if length <= self.inner.length {
+            self.inner.length = length;
+            Ok(())
+        } else {
+            self.extend(&vec![value; length - self.inner.length])
+                .with_context("resizing ElementBuffer")
+        }
+    }
+    /// Reallocate the buffer to make space for at least `additional` more elements,
+    /// keeping its contents
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the buffer has immutable storage,
+    /// or we are out of memory.
+    pub fn reserve(&mut self, additional: usize) -> Result<(),OwlError> {
+        if self.inner.length + additional > self.inner.capacity {
+            self.inner.reallocate(self.inner.length + additional)
+                .with_context("reserving space in ElementBuffer")?;
+        }
+        Ok(())
+    }
+    // let's see if we can't limit the scope to crate.
     pub(crate) fn bind(&self) {
-        ox::bind_buffer(ox::BufferType::ElementArray, Some(self.inner.id))
+        ox::bind_buffer(ox::BufferType::ElementArray, Some(&self.inner.id))
             .expect("buffer should not be deleted yet");
     }
     pub(crate) fn unbind() {
         ox::bind_buffer(ox::BufferType::ElementArray, None).expect("binding 0 always succeeds");
     }
+    /// The element array binding belongs to the bound vertex array, so the buffer's data is
+    /// specified through `COPY_WRITE` instead, leaving every vertex array's indices alone
+    fn bind_data(&self) {
+        ox::bind_buffer(ox::BufferType::CopyWrite, Some(&self.inner.id))
+            .expect("buffer should not be deleted yet");
+    }
 }
-impl<T: ToByteVec> Drop for ElementBuffer<T> {
-    fn drop(&mut self) {
-        ox::delete_buffer(self.inner.id);
+impl<T: IndexElement> ElementBuffer<T> {
+    /// Map the whole buffer, to be read through the returned guard
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the buffer is empty.
+    pub fn map_read(&mut self) -> Result<MappedBuffer<'_, T>, OwlError> {
+        // mapped through COPY_WRITE, which leaves the vertex arrays' element bindings alone
+        MappedBuffer::new(ox::BufferType::CopyWrite, &self.inner.id, 0..self.inner.length)
+            .with_context("mapping ElementBuffer for reading")
+    }
+    /// Map the whole buffer, to be written through the returned guard
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the buffer is empty.
+    pub fn map_write(&mut self) -> Result<MappedBufferMut<'_, T>, OwlError> {
+        // mapped through COPY_WRITE, which leaves the vertex arrays' element bindings alone
+        MappedBufferMut::new(ox::BufferType::CopyWrite, &self.inner.id, 0..self.inner.length, MapFlags::empty())
+            .with_context("mapping ElementBuffer for writing")
+    }
+    /// Map the elements in `range`, to be written (and, with [`MapFlags::Read`], read)
+    /// through the returned guard
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the range is empty or exceeds the buffer,
+    /// or [`MapFlags::Read`] is combined with any other flag.
+    pub fn map_range(&mut self, range: Range<usize>, flags: MapFlags) -> Result<MappedBufferMut<'_, T>, OwlError> {
+        self.inner.check_bounds(range.start, range.len()).with_context("mapping range of ElementBuffer")?;
+        // mapped through COPY_WRITE, which leaves the vertex arrays' element bindings alone
+        MappedBufferMut::new(ox::BufferType::CopyWrite, &self.inner.id, range, flags)
+            .with_context("mapping range of ElementBuffer")
+    }
+    /// The contents of the buffer, if it was created with [`BufferStorageFlags::MapPersistent`]
+    /// and [`BufferStorageFlags::MapRead`]
+    #[must_use]
+    pub fn persistent(&self) -> Option<&[T]> {
+        self.inner.persistent()
+    }
+    /// The contents of the buffer, if it was created with [`BufferStorageFlags::MapPersistent`]
+    /// and [`BufferStorageFlags::MapWrite`]. Without [`BufferStorageFlags::MapCoherent`], writes
+    /// must be made visible with [`ElementBuffer::flush_persistent`].
+    ///
+    /// # Notes
+    /// Unless it was also created with [`BufferStorageFlags::MapRead`], reading from it is allowed,
+    /// but its contents are undefined, and likely to be slow to access.
+    pub fn persistent_mut(&mut self) -> Option<&mut [T]> {
+        self.inner.persistent_mut()
+    }
+    /// Make writes through [`ElementBuffer::persistent_mut`] to the elements in `range` visible to OpenGL;
+    /// unnecessary, though harmless, for coherent buffers.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the buffer is not persistently mapped for writing,
+    /// or the range exceeds it.
+    pub fn flush_persistent(&self, range: Range<usize>) -> Result<(),OwlError> {
+        self.bind_data();
+        self.inner.flush_persistent(ox::BufferType::CopyWrite, range)
+            .with_context("flushing ElementBuffer")
+    }
+}
+impl<T: IndexElement> ElementBuffer<T> {
+    /// Read the elements in `range` back from the buffer
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the range exceeds the buffer,
+    /// or the buffer is being mapped.
+    pub fn read(&self, range: Range<usize>) -> Result<Vec<T>, OwlError> {
+        if range.start > range.end {
+            return Err(OwlError::custom("range start > range end"))
+                .with_context("reading ElementBuffer");
+        }
+        self.inner.check_bounds(range.start, range.len()).with_context("reading ElementBuffer")?;
+        let bytes = read_bytes(&self.inner.id, range.start * T::BYTE_SIZE, range.len() * T::BYTE_SIZE)
+            .with_context("reading ElementBuffer")?;
+        Ok(T::from_byte_vec(&bytes))
+    }
+}
+/// A wrapper around [Buffer], that allows functions using it to specify the `UNIFORM_BUFFER` target.
+/// It stores a single `T` in the `std140` layout, to be shared between programs through a
+/// binding point.
+#[derive(Debug, PartialEq, Eq)]
+pub struct UniformBuffer<T: Std140>(Buffer<T>);
+// INVARIANT: will not be deleted until it is dropped
+impl<T: Std140> UniformBuffer<T> {
+    fn std140_bytes(data: &T) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(T::STD140_SIZE);
+        data.write_std140(&mut bytes);
+        bytes
+    }
+    /// # Errors
+    ///
+    /// ## Out of Memory
+    /// This function will return an error if we are out of memory, and thus no more data can be buffered.
+    pub fn new(data: &T, usage: BufferUsage) -> Result<Self, OwlError> {
+        let created = Self(Buffer::new(T::STD140_SIZE, 1, usage));
+        created.bind();
+        // buffer cannot be immutable, so must be out of memory
+        ox::buffer_data(ox::BufferType::Uniform, Self::std140_bytes(data), usage)
+            .with_context("creating UniformBuffer")?;
+        Ok(created)
+    }
+    /// # Errors
+    ///
+    /// This function will return an error if the buffer is being mapped.
+    pub fn update(&mut self, data: &T) -> Result<(),OwlError> {
+        self.bind();
+        ox::buffer_subdata(ox::BufferType::Uniform, Self::std140_bytes(data), 0).map_err(|e| {
+            match e {
+                ox::OxError::BaseError(crate::OriginalError::InvalidOperation) => {
+                    e.with_message("buffer is being mapped")
+                },
+                _ => e.with_message("no other errors should be produced")
+            }
+            .with_context("updating UniformBuffer")
+        })
+    }
+    /// Bind the buffer to the indexed uniform `binding` point, to be read by any uniform block
+    /// linked to it with [`crate::Program::bind_uniform_block`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `binding` >= `GL_MAX_UNIFORM_BUFFER_BINDINGS`.
+    pub fn bind_to(&self, binding: u32) -> Result<(),OwlError> {
+        ox::bind_buffer_base(ox::IndexedBufferType::Uniform, binding, Some(&self.0.id)).map_err(|e| {
+            match e {
+                ox::OxError::BaseError(crate::OriginalError::InvalidValue) => {
+                    e.with_message("binding >= GL_MAX_UNIFORM_BUFFER_BINDINGS")
+                },
+                _ => e.with_message("no other errors should be produced")
+            }
+            .with_context("binding UniformBuffer")
+        })
+    }
+    /// Read the value back from the buffer
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the buffer is being mapped.
+    pub fn read(&self) -> Result<T, OwlError> {
+        let bytes = read_bytes(&self.0.id, 0, T::STD140_SIZE)
+            .with_context("reading UniformBuffer")?;
+        Ok(T::read_std140(&bytes))
+    }
+    /// Copy the contents of `source` into this buffer, without a round trip through the CPU
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if either buffer is being mapped.
+    pub fn copy_from(&mut self, source: &Self) -> Result<(),OwlError> {
+        copy_bytes(&source.0.id, &self.0.id, 0, 0, T::STD140_SIZE)
+            .with_context("copying to UniformBuffer")
+    }
+    /// A new buffer holding a copy of the value, e.g. to be bound elsewhere and diverge from this one
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if we are out of memory.
+    pub fn duplicate(&self) -> Result<Self, OwlError> {
+        self.0.duplicate().map(Self).with_context("duplicating UniformBuffer")
+    }
+    pub(crate) fn bind(&self) {
+        ox::bind_buffer(ox::BufferType::Uniform, Some(&self.0.id))
+            .expect("buffer should not be deleted yet");
+    }
+    pub(crate) fn unbind() {
+        ox::bind_buffer(ox::BufferType::Uniform, None)
+            .expect("binding 0 always succeeds");
+    }
+}
+/// A wrapper around [Buffer], that allows functions using it to specify the `SHADER_STORAGE_BUFFER` target.
+///
+/// It stores a fixed `H`, followed by a runtime-sized array of `E`, in the `std430` layout.
+/// Either may be omitted by using `()`.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ShaderStorageBuffer<H: Std430, E: Std430 = ()> {
+    inner: Buffer<(H, E)>,
+}
+// INVARIANT: will not be deleted until it is dropped
+impl<H: Std430, E: Std430> ShaderStorageBuffer<H, E> {
+    /// The offset of the runtime-sized array, after the fixed part of the buffer
+    const ELEMENTS_OFFSET: usize = H::STD430_SIZE.next_multiple_of(E::STD430_ALIGNMENT);
+    const ELEMENT_STRIDE: usize = E::STD430_SIZE.next_multiple_of(E::STD430_ALIGNMENT);
+    fn std430_bytes(elements: &[E]) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(elements.len() * Self::ELEMENT_STRIDE);
+        for (i, element) in elements.iter().enumerate() {
+            element.write_std430(&mut bytes);
+            bytes.resize((i + 1) * Self::ELEMENT_STRIDE, 0);
+        }
+        bytes
+    }
+    /// # Errors
+    ///
+    /// ## Out of Memory
+    /// This function will return an error if we are out of memory, and thus no more data can be buffered.
+    pub fn new(header: &H, elements: &[E], usage: BufferUsage) -> Result<Self, OwlError> {
+        let created = Self { inner: Buffer::new(Self::ELEMENT_STRIDE, elements.len(), usage) };
+        created.bind();
+        let mut bytes = Vec::with_capacity(Self::ELEMENTS_OFFSET + elements.len() * Self::ELEMENT_STRIDE);
+        header.write_std430(&mut bytes);
+        bytes.resize(Self::ELEMENTS_OFFSET, 0);
+        bytes.extend(Self::std430_bytes(elements));
+        // buffer cannot be immutable, so must be out of memory
+        ox::buffer_data(ox::BufferType::ShaderStorage, bytes, usage)
+            .with_context("creating ShaderStorageBuffer")?;
+        Ok(created)
+    }
+    /// The number of elements in the runtime-sized array
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.inner.length
+    }
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.inner.length == 0
+    }
+    /// # Errors
+    ///
+    /// This function will return an error if the buffer is being mapped.
+    pub fn update_header(&mut self, header: &H) -> Result<(),OwlError> {
+        self.bind();
+        let mut bytes = Vec::with_capacity(H::STD430_SIZE);
+        header.write_std430(&mut bytes);
+        ox::buffer_subdata(ox::BufferType::ShaderStorage, bytes, 0).map_err(|e| {
+            match e {
+                ox::OxError::BaseError(crate::OriginalError::InvalidOperation) => {
+                    e.with_message("buffer is being mapped")
+                },
+                _ => e.with_message("no other errors should be produced")
+            }
+            .with_context("updating ShaderStorageBuffer header")
+        })
+    }
+    /// Overwrite the elements of the runtime-sized array, starting from the element at `offset`
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the elements at the given offset overflow the array,
+    /// or if the buffer is being mapped.
+    pub fn update_elements(&mut self, elements: &[E], offset: usize) -> Result<(),OwlError> {
+        if offset + elements.len() > self.inner.length {
+            return Err(OwlError::custom("offset + elements length > array length"))
+                .with_context("updating ShaderStorageBuffer elements");
+        }
+        self.bind();
+        // offset is measured in elements, so as u8s, offset by the header
+        ox::buffer_subdata(ox::BufferType::ShaderStorage, Self::std430_bytes(elements),
+            Self::ELEMENTS_OFFSET + offset * Self::ELEMENT_STRIDE).map_err(|e| {
+            match e {
+                ox::OxError::BaseError(crate::OriginalError::InvalidOperation) => {
+                    e.with_message("buffer is being mapped")
+                },
+                _ => e.with_message("no other errors should be produced")
+            }
+            .with_context("updating ShaderStorageBuffer elements")
+        })
+    }
+    /// Bind the buffer to the indexed shader storage `binding` point, to be read by any shader
+    /// storage block declared with that binding.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `binding` >= `GL_MAX_SHADER_STORAGE_BUFFER_BINDINGS`.
+    pub fn bind_to(&self, binding: u32) -> Result<(),OwlError> {
+        ox::bind_buffer_base(ox::IndexedBufferType::ShaderStorage, binding, Some(&self.inner.id)).map_err(|e| {
+            match e {
+                ox::OxError::BaseError(crate::OriginalError::InvalidValue) => {
+                    e.with_message("binding >= GL_MAX_SHADER_STORAGE_BUFFER_BINDINGS")
+                },
+                _ => e.with_message("no other errors should be produced")
+            }
+            .with_context("binding ShaderStorageBuffer")
+        })
+    }
+    /// Read the header back from the buffer
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the buffer is being mapped.
+    pub fn read_header(&self) -> Result<H, OwlError> {
+        let bytes = read_bytes(&self.inner.id, 0, H::STD430_SIZE)
+            .with_context("reading ShaderStorageBuffer header")?;
+        Ok(H::read_std430(&bytes))
+    }
+    /// Read the elements of the runtime-sized array in `range` back from the buffer
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the range exceeds the array,
+    /// or the buffer is being mapped.
+    pub fn read_elements(&self, range: Range<usize>) -> Result<Vec<E>, OwlError> {
+        if range.start > range.end {
+            return Err(OwlError::custom("range start > range end"))
+                .with_context("reading ShaderStorageBuffer elements");
+        }
+        if range.end > self.inner.length {
+            return Err(OwlError::custom("range exceeds array length"))
+                .with_context("reading ShaderStorageBuffer elements");
+        }
+        let bytes = read_bytes(&self.inner.id, Self::ELEMENTS_OFFSET + range.start * Self::ELEMENT_STRIDE,
+            range.len() * Self::ELEMENT_STRIDE)
+            .with_context("reading ShaderStorageBuffer elements")?;
+        // the stride may be 0, for ()
+        Ok((0..range.len()).map(|i| E::read_std430(&bytes[i * Self::ELEMENT_STRIDE..])).collect())
+    }
+    /// Copy the header of `source` into this buffer, without a round trip through the CPU
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if either buffer is being mapped.
+    pub fn copy_header_from(&mut self, source: &Self) -> Result<(),OwlError> {
+        copy_bytes(&source.inner.id, &self.inner.id, 0, 0, H::STD430_SIZE)
+            .with_context("copying header to ShaderStorageBuffer")
+    }
+    /// Copy `length` elements of the runtime-sized array of `source`, starting from `source_offset`,
+    /// into this buffer's starting from `destination_offset`, without a round trip through the CPU
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if either range overflows its array,
+    /// or if either buffer is being mapped.
+    pub fn copy_elements_from(&mut self, source: &Self, source_offset: usize, destination_offset: usize,
+        length: usize) -> Result<(),OwlError> {
+        if source_offset + length > source.inner.length {
+            return Err(OwlError::custom("source offset + length > source array length"))
+                .with_context("copying elements to ShaderStorageBuffer");
+        }
+        if destination_offset + length > self.inner.length {
+            return Err(OwlError::custom("destination offset + length > destination array length"))
+                .with_context("copying elements to ShaderStorageBuffer");
+        }
+        copy_bytes(&source.inner.id, &self.inner.id,
+            Self::ELEMENTS_OFFSET + source_offset * Self::ELEMENT_STRIDE,
+            Self::ELEMENTS_OFFSET + destination_offset * Self::ELEMENT_STRIDE,
+            length * Self::ELEMENT_STRIDE)
+            .with_context("copying elements to ShaderStorageBuffer")
+    }
+    /// A new buffer with a copy of both the header and the runtime-sized array, made on the GPU
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if we are out of memory.
+    pub fn duplicate(&self) -> Result<Self, OwlError> {
+        self.inner.duplicate().map(|inner| Self { inner }).with_context("duplicating ShaderStorageBuffer")
+    }
+    pub(crate) fn bind(&self) {
+        ox::bind_buffer(ox::BufferType::ShaderStorage, Some(&self.inner.id))
+            .expect("buffer should not be deleted yet");
+    }
+    pub(crate) fn unbind() {
+        ox::bind_buffer(ox::BufferType::ShaderStorage, None)
+            .expect("binding 0 always succeeds");
+    }
+}
+/// A wrapper around [Buffer], that allows functions using it to specify the `ATOMIC_COUNTER_BUFFER` target.
+///
+/// It stores a number of `uint` counters, which shaders can increment and decrement atomically,
+/// e.g. to count the objects surviving culling.
+#[derive(Debug, PartialEq, Eq)]
+pub struct AtomicCounterBuffer(Buffer<u32>);
+// INVARIANT: will not be deleted until it is dropped
+impl AtomicCounterBuffer {
+    /// Create a buffer holding a counter for each of `initial`, starting from its value
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if we are out of memory.
+    pub fn new(initial: &[u32], usage: BufferUsage) -> Result<Self, OwlError> {
+        let created = Self(Buffer::new(std::mem::size_of::<u32>(), initial.len(), usage));
+        created.bind();
+        let allocated = ox::buffer_data_slice(ox::BufferType::AtomicCounter, initial, usage)
+            .with_context("creating AtomicCounterBuffer");
+        Self::unbind();
+        allocated.map(|()| created)
+    }
+    /// The number of counters
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.0.length
+    }
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.0.length == 0
+    }
+    /// Bind the buffer to the indexed atomic counter `binding` point, to be used by any counters
+    /// declared with that binding.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `binding` >= `GL_MAX_ATOMIC_COUNTER_BUFFER_BINDINGS`.
+    pub fn bind_to(&self, binding: u32) -> Result<(),OwlError> {
+        ox::bind_buffer_base(ox::IndexedBufferType::AtomicCounter, binding, Some(&self.0.id)).map_err(|e| {
+            match e {
+                ox::OxError::BaseError(crate::OriginalError::InvalidValue) => {
+                    e.with_message("binding >= GL_MAX_ATOMIC_COUNTER_BUFFER_BINDINGS")
+                },
+                _ => e.with_message("no other errors should be produced")
+            }
+            .with_context("binding AtomicCounterBuffer")
+        })
+    }
+    /// Set every counter to `value`, typically 0 before each pass that counts
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the buffer is being mapped.
+    pub fn reset(&mut self, value: u32) -> Result<(),OwlError> {
+        self.update(&vec![value; self.0.length], 0)
+            .with_context("resetting AtomicCounterBuffer")
+    }
+    /// Set the counters from `offset` onwards to `values`
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the values at the given offset overflow the buffer,
+    /// or the buffer is being mapped.
+    pub fn update(&mut self, values: &[u32], offset: usize) -> Result<(),OwlError> {
+        self.0.check_bounds(offset, values.len()).with_context("updating AtomicCounterBuffer")?;
+        self.bind();
+        let updated = ox::buffer_subdata_slice(ox::BufferType::AtomicCounter, values, offset).map_err(|e| {
+            match e {
+                ox::OxError::BaseError(crate::OriginalError::InvalidOperation) => {
+                    e.with_message("buffer is being mapped")
+                },
+                _ => e.with_message("no other errors should be produced")
+            }
+            .with_context("updating AtomicCounterBuffer")
+        });
+        Self::unbind();
+        updated
+    }
+    /// Copy `length` counters of `source`, starting from `source_offset`, into this buffer
+    /// starting from `destination_offset`, without a round trip through the CPU, e.g. to keep
+    /// the totals of one pass while the next counts
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if either range overflows its buffer,
+    /// or either buffer is being mapped.
+    pub fn copy_from(&mut self, source: &Self, source_offset: usize, destination_offset: usize,
+        length: usize) -> Result<(),OwlError> {
+        // atomic writes from shaders are incoherent, so must be made visible to buffer copies
+        ox::memory_barrier(ox::MemoryBarrierFlags::BufferUpdate);
+        self.0.copy_from(&source.0, source_offset, destination_offset, length)
+            .with_context("copying to AtomicCounterBuffer")
+    }
+    /// A new buffer with a copy of this one's counters and storage, made without a round trip through
+    /// the CPU
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if we are out of memory.
+    pub fn duplicate(&self) -> Result<Self, OwlError> {
+        ox::memory_barrier(ox::MemoryBarrierFlags::BufferUpdate);
+        self.0.duplicate().map(Self).with_context("duplicating AtomicCounterBuffer")
+    }
+    /// Read every counter back, once the shaders incrementing them have finished
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the buffer is being mapped.
+    pub fn read(&self) -> Result<Vec<u32>, OwlError> {
+        // atomic writes from shaders are incoherent, so must be made visible to buffer reads
+        ox::memory_barrier(ox::MemoryBarrierFlags::BufferUpdate);
+        let bytes = read_bytes(&self.0.id, 0, self.0.length * std::mem::size_of::<u32>())
+            .with_context("reading AtomicCounterBuffer")?;
+        Ok(u32::from_byte_vec(&bytes))
+    }
+    pub(crate) fn bind(&self) {
+        ox::bind_buffer(ox::BufferType::AtomicCounter, Some(&self.0.id))
+            .expect("buffer should not be deleted yet");
+    }
+    pub(crate) fn unbind() {
+        ox::bind_buffer(ox::BufferType::AtomicCounter, None)
+            .expect("binding 0 always succeeds");
+    }
+}
+pub use ox::{PixelFormat, PixelDataType};
+/// A rectangle of pixels, from its lower left corner
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PixelRectangle {
+    pub x: i32,
+    pub y: i32,
+    pub width: usize,
+    pub height: usize,
+}
+/// A wrapper around [Buffer], that allows functions using it to specify the `PIXEL_PACK_BUFFER` target.
+///
+/// It is the destination of pixel readbacks, from the framebuffer or a [`crate::Texture2D`], which are
+/// queued rather than waited on, so that the transfer overlaps with rendering: poll
+/// [`PixelPackBuffer::is_ready`] before reading or mapping it, to avoid blocking.
+#[derive(Debug)]
+pub struct PixelPackBuffer<T: Pod> {
+    inner: Buffer<T>,
+    /// Signalled once the last readback has completed
+    fence: Option<ox::Fence>,
+}
+// INVARIANT: will not be deleted until it is dropped
+impl<T: Pod> PixelPackBuffer<T> {
+    /// Create a buffer with space for `length` elements, e.g. pixels, with undefined contents
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if we are out of memory.
+    pub fn new(length: usize, usage: BufferUsage) -> Result<Self, OwlError> {
+        let created = Self { inner: Buffer::new(std::mem::size_of::<T>(), length, usage), fence: None };
+        ox::bind_buffer(ox::BufferType::PixelPack, Some(&created.inner.id))
+            .expect("buffer was just generated");
+        let allocated = ox::buffer_data_uninitialised(ox::BufferType::PixelPack, length * std::mem::size_of::<T>(), usage)
+            .with_message("out of memory")
+            .with_context("creating PixelPackBuffer");
+        Self::unbind();
+        allocated.map(|()| created)
+    }
+    /// The number of elements there is space for
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.inner.length
+    }
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.inner.length == 0
+    }
+    /// Queue a read of the `rectangle` of pixels from the framebuffer, in the given format, into the
+    /// buffer starting from element `offset`, without waiting for it to complete
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the pixels overflow the buffer, `offset` is misaligned
+    /// for the data type, the format and data type are incompatible with each other or the framebuffer,
+    /// or the buffer is being mapped.
+    pub fn read_pixels(&mut self, rectangle: PixelRectangle, format: PixelFormat, data_type: PixelDataType,
+        offset: usize) -> Result<(),OwlError> {
+        ox::bind_buffer(ox::BufferType::PixelPack, Some(&self.inner.id))
+            .expect("buffer should not be deleted yet");
+        // SAFETY: this buffer was just bound to GL_PIXEL_PACK_BUFFER
+        let read = unsafe { ox::read_pixels(rectangle.x, rectangle.y, rectangle.width, rectangle.height,
+            format, data_type, offset * std::mem::size_of::<T>()) }.map_err(|e| {
+            match e {
+                ox::OxError::BaseError(crate::OriginalError::InvalidOperation) => {
+                    e.with_message("either the pixels overflow the buffer, the offset is misaligned, the format \
+                        and data type are incompatible, or the buffer is being mapped")
+                },
+                ox::OxError::BaseError(crate::OriginalError::InvalidFramebufferOperation) => {
+                    e.with_message("the framebuffer is incomplete")
+                },
+                _ => e.with_message("no other errors should be produced")
+            }
+            .with_context("reading pixels into PixelPackBuffer")
+        });
+        // pixel transfers to client memory expect nothing to be bound
+        Self::unbind();
+        read?;
+        if let Some(fence) = self.fence.replace(ox::fence_sync()) {
+            ox::delete_sync(fence);
+        }
+        Ok(())
+    }
+    /// Queue a read of the whole of mipmap `level` of `texture`, in the given format, into the buffer
+    /// starting from element `offset`, without waiting for it to complete
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the level does not exist, the pixels overflow the buffer,
+    /// `offset` is misaligned for the data type, the format and data type are incompatible with each other
+    /// or the texture, or the buffer is being mapped.
+    pub fn read_texture(&mut self, texture: &crate::Texture2D, level: usize, format: PixelFormat,
+        data_type: PixelDataType, offset: usize) -> Result<(),OwlError> {
+        texture.check_level(level).with_context("reading texture into PixelPackBuffer")?;
+        ox::bind_buffer(ox::BufferType::PixelPack, Some(&self.inner.id))
+            .expect("buffer should not be deleted yet");
+        texture.bind();
+        // SAFETY: this buffer was just bound to GL_PIXEL_PACK_BUFFER
+        let read = unsafe { ox::get_tex_image(ox::TextureTarget::Texture2D, level, format, data_type,
+            offset * std::mem::size_of::<T>()) }.map_err(|e| {
+            match e {
+                ox::OxError::BaseError(crate::OriginalError::InvalidOperation) => {
+                    e.with_message("either the pixels overflow the buffer, the offset is misaligned, the format \
+                        and data type are incompatible, or the buffer is being mapped")
+                },
+                _ => e.with_message("no other errors should be produced")
+            }
+            .with_context("reading texture into PixelPackBuffer")
+        });
+        // pixel transfers to client memory expect nothing to be bound
+        crate::Texture2D::unbind();
+        Self::unbind();
+        read?;
+        if let Some(fence) = self.fence.replace(ox::fence_sync()) {
+            ox::delete_sync(fence);
+        }
+        Ok(())
+    }
+    /// Queue a copy of `length` elements of `source`, starting from `source_offset`, into this buffer
+    /// starting from `destination_offset`, without a round trip through the CPU. Like a readback, it is
+    /// not waited on, and follows any readback still pending into `source`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if either range overflows its buffer,
+    /// or either buffer is being mapped.
+    pub fn copy_from(&mut self, source: &Self, source_offset: usize, destination_offset: usize,
+        length: usize) -> Result<(),OwlError> {
+        self.inner.copy_from(&source.inner, source_offset, destination_offset, length)
+            .with_context("copying to PixelPackBuffer")?;
+        if let Some(fence) = self.fence.replace(ox::fence_sync()) {
+            ox::delete_sync(fence);
+        }
+        Ok(())
+    }
+    /// A new buffer with a copy of this one's contents and storage, made without a round trip through
+    /// the CPU, e.g. to keep a readback while the next is queued into this one
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if we are out of memory.
+    pub fn duplicate(&self) -> Result<Self, OwlError> {
+        self.inner.duplicate()
+            .map(|inner| Self { inner, fence: Some(ox::fence_sync()) })
+            .with_context("duplicating PixelPackBuffer")
+    }
+    /// Whether the last readback has completed, so that reading or mapping the buffer will not block
+    #[must_use]
+    pub fn is_ready(&self) -> bool {
+        // flushing, so that the fence is sure to be signalled eventually, however long this is polled
+        self.fence.as_ref().is_none_or(|fence| matches!(ox::client_wait_sync(fence, true, 0),
+            Ok(ox::WaitStatus::AlreadySignaled | ox::WaitStatus::ConditionSatisfied)))
+    }
+    /// Map the whole buffer, to be read through the returned guard, blocking until the last readback
+    /// has completed
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the buffer is empty.
+    pub fn map_read(&mut self) -> Result<MappedBuffer<'_, T>, OwlError> {
+        // mapped through COPY_WRITE, which leaves the pixel transfer bindings alone
+        MappedBuffer::new(ox::BufferType::CopyWrite, &self.inner.id, 0..self.inner.length)
+            .with_context("mapping PixelPackBuffer for reading")
+    }
+    fn unbind() {
+        ox::bind_buffer(ox::BufferType::PixelPack, None)
+            .expect("binding 0 always succeeds");
+    }
+}
+impl<T: Pod + FromByteVec> PixelPackBuffer<T> {
+    /// Read the elements in `range` back from the buffer, blocking until the last readback has completed
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the range exceeds the buffer,
+    /// or the buffer is being mapped.
+    pub fn read(&self, range: Range<usize>) -> Result<Vec<T>, OwlError> {
+        if range.start > range.end {
+            return Err(OwlError::custom("range start > range end"))
+                .with_context("reading PixelPackBuffer");
+        }
+        self.inner.check_bounds(range.start, range.len()).with_context("reading PixelPackBuffer")?;
+        let bytes = read_bytes(&self.inner.id, range.start * T::BYTE_SIZE, range.len() * T::BYTE_SIZE)
+            .with_context("reading PixelPackBuffer")?;
+        Ok(T::from_byte_vec(&bytes))
+    }
+}
+impl<T: Pod> Drop for PixelPackBuffer<T> {
+    fn drop(&mut self) {
+        if let Some(fence) = self.fence.take() {
+            ox::delete_sync(fence);
+        }
+    }
+}
+
+/// A wrapper around [Buffer], that allows functions using it to specify the `PIXEL_UNPACK_BUFFER` target.
+///
+/// It stages pixel data, written from slices or through a mapping, to be the source of
+/// [`crate::Texture2D::upload_from`].
+#[derive(Debug, PartialEq, Eq)]
+pub struct PixelUnpackBuffer<T: Pod>(Buffer<T>);
+// INVARIANT: will not be deleted until it is dropped
+impl<T: Pod> PixelUnpackBuffer<T> {
+    /// # Errors
+    ///
+    /// This function will return an error if we are out of memory.
+    pub fn new(data: &[T], usage: BufferUsage) -> Result<Self, OwlError> {
+        let created = Self(Buffer::new(std::mem::size_of::<T>(), data.len(), usage));
+        created.bind();
+        let allocated = ox::buffer_data_slice(ox::BufferType::PixelUnpack, data, usage)
+            .with_context("creating PixelUnpackBuffer");
+        Self::unbind();
+        allocated.map(|()| created)
+    }
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.0.length
+    }
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.0.length == 0
+    }
+    /// # Errors
+    ///
+    /// This function will return an error if the data at the given offset overflows the buffer,
+    /// or if the buffer is being mapped.
+    pub fn update(&mut self, data: &[T], offset: usize) -> Result<(),OwlError> {
+        self.0.check_bounds(offset, data.len()).with_context("updating PixelUnpackBuffer")?;
+        self.bind();
+        let updated = ox::buffer_subdata_slice(ox::BufferType::PixelUnpack, data, offset).map_err(|e| {
+            match e {
+                ox::OxError::BaseError(crate::OriginalError::InvalidOperation) => {
+                    e.with_message("buffer is being mapped")
+                },
+                _ => e.with_message("no other errors should be produced")
+            }
+            .with_context("updating PixelUnpackBuffer")
+        });
+        Self::unbind();
+        updated
+    }
+    /// Map the whole buffer, to be written through the returned guard
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the buffer is empty.
+    pub fn map_write(&mut self) -> Result<MappedBufferMut<'_, T>, OwlError> {
+        // mapped through COPY_WRITE, which leaves the pixel transfer bindings alone
+        MappedBufferMut::new(ox::BufferType::CopyWrite, &self.0.id, 0..self.0.length, MapFlags::InvalidateBuffer)
+            .with_context("mapping PixelUnpackBuffer for writing")
+    }
+    /// Copy `length` elements of `source`, starting from `source_offset`, into this buffer
+    /// starting from `destination_offset`, without a round trip through the CPU
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if either range overflows its buffer,
+    /// or either buffer is being mapped.
+    pub fn copy_from(&mut self, source: &Self, source_offset: usize, destination_offset: usize,
+        length: usize) -> Result<(),OwlError> {
+        self.0.copy_from(&source.0, source_offset, destination_offset, length)
+            .with_context("copying to PixelUnpackBuffer")
+    }
+    /// A new buffer with a copy of this one's pixels and storage, made without a round trip through
+    /// the CPU, e.g. to upload to several textures while this one is rewritten
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if we are out of memory.
+    pub fn duplicate(&self) -> Result<Self, OwlError> {
+        self.0.duplicate().map(Self).with_context("duplicating PixelUnpackBuffer")
+    }
+    /// [`crate::Texture2D::upload_from`] reads from the bound buffer, rather than client memory, until it is unbound
+    pub(crate) fn bind(&self) {
+        ox::bind_buffer(ox::BufferType::PixelUnpack, Some(&self.0.id))
+            .expect("buffer should not be deleted yet");
+    }
+    pub(crate) fn unbind() {
+        ox::bind_buffer(ox::BufferType::PixelUnpack, None)
+            .expect("binding 0 always succeeds");
+    }
+}
+/// A wrapper around [Buffer], that allows functions using it to specify the `DRAW_INDIRECT_BUFFER` target.
+///
+/// Its commands are read by the GPU when drawing with [`crate::draw_indirect`] or
+/// [`crate::multi_draw_indirect`], so that many objects can be drawn in a single call.
+#[derive(Debug, PartialEq, Eq)]
+pub struct DrawIndirectBuffer<C: IndirectCommand>(Buffer<C>);
+// INVARIANT: will not be deleted until it is dropped
+impl<C: IndirectCommand> DrawIndirectBuffer<C> {
+    /// # Errors
+    ///
+    /// This function will return an error if we are out of memory.
+    pub fn new(commands: &[C], usage: BufferUsage) -> Result<Self, OwlError> {
+        let created = Self(Buffer::new(std::mem::size_of::<C>(), commands.len(), usage));
+        created.bind();
+        let allocated = ox::buffer_data_slice(ox::BufferType::DrawIndirect, commands, usage)
+            .with_context("creating DrawIndirectBuffer");
+        Self::unbind();
+        allocated.map(|()| created)
+    }
+    /// Create a buffer with immutable storage, which cannot be resized, and can only be updated
+    /// as the flags allow
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the commands are empty, the flags are invalid,
+    /// or we are out of memory.
+    pub fn new_immutable(commands: &[C], flags: BufferStorageFlags) -> Result<Self, OwlError> {
+        let mut created = Self(Buffer::new(std::mem::size_of::<C>(), commands.len(), BufferUsage::StaticDraw));
+        created.bind();
+        let allocated = created.0.allocate_immutable(ox::BufferType::DrawIndirect, commands, flags)
+            .with_context("creating immutable DrawIndirectBuffer");
+        Self::unbind();
+        allocated.map(|()| created)
+    }
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.0.length
+    }
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.0.length == 0
+    }
+    /// # Errors
+    ///
+    /// This function will return an error if the commands at the given offset overflow the buffer,
+    /// if the buffer is being mapped, or if it has immutable storage created without
+    /// [`BufferStorageFlags::DynamicStorage`].
+    pub fn update(&mut self, commands: &[C], offset: usize) -> Result<(),OwlError> {
+        self.0.check_updatable().with_context("updating DrawIndirectBuffer")?;
+        self.0.check_bounds(offset, commands.len()).with_context("updating DrawIndirectBuffer")?;
+        self.bind();
+        let updated = ox::buffer_subdata_slice(ox::BufferType::DrawIndirect, commands, offset).map_err(|e| {
+            match e {
+                ox::OxError::BaseError(crate::OriginalError::InvalidOperation) => {
+                    e.with_message("buffer is being mapped")
+                },
+                _ => e.with_message("no other errors should be produced")
+            }
+            .with_context("updating DrawIndirectBuffer")
+        });
+        Self::unbind();
+        updated
+    }
+    /// Map the commands in `range`, to be written through the returned guard
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the range is empty or exceeds the buffer,
+    /// or the flags are incompatible.
+    pub fn map_range(&mut self, range: Range<usize>, flags: MapFlags) -> Result<MappedBufferMut<'_, C>, OwlError> {
+        self.0.check_bounds(range.start, range.len()).with_context("mapping DrawIndirectBuffer range")?;
+        MappedBufferMut::new(ox::BufferType::DrawIndirect, &self.0.id, range, flags)
+            .with_context("mapping DrawIndirectBuffer range")
+    }
+    /// Read the commands in `range` back from the buffer, e.g. after a compute shader has written them
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the range exceeds the buffer,
+    /// or the buffer is being mapped.
+    pub fn read(&self, range: Range<usize>) -> Result<Vec<C>, OwlError> {
+        if range.start > range.end {
+            return Err(OwlError::custom("range start > range end"))
+                .with_context("reading DrawIndirectBuffer");
+        }
+        self.0.check_bounds(range.start, range.len()).with_context("reading DrawIndirectBuffer")?;
+        let bytes = read_bytes(&self.0.id, range.start * C::BYTE_SIZE, range.len() * C::BYTE_SIZE)
+            .with_context("reading DrawIndirectBuffer")?;
+        Ok(C::from_byte_vec(&bytes))
+    }
+    /// Copy `length` commands of `source`, starting from `source_offset`, into this buffer
+    /// starting from `destination_offset`, without a round trip through the CPU
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if either range overflows its buffer,
+    /// or either buffer is being mapped.
+    pub fn copy_from(&mut self, source: &Self, source_offset: usize, destination_offset: usize,
+        length: usize) -> Result<(),OwlError> {
+        self.0.copy_from(&source.0, source_offset, destination_offset, length)
+            .with_context("copying to DrawIndirectBuffer")
+    }
+    /// A new buffer with a copy of this one's commands and storage, made without a round trip through
+    /// the CPU, e.g. to be culled differently
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if we are out of memory.
+    pub fn duplicate(&self) -> Result<Self, OwlError> {
+        self.0.duplicate().map(Self).with_context("duplicating DrawIndirectBuffer")
+    }
+    pub(crate) fn bind(&self) {
+        ox::bind_buffer(ox::BufferType::DrawIndirect, Some(&self.0.id))
+            .expect("buffer should not be deleted yet");
+    }
+    pub(crate) fn unbind() {
+        ox::bind_buffer(ox::BufferType::DrawIndirect, None)
+            .expect("binding 0 always succeeds");
     }
 }
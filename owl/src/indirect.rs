@@ -0,0 +1,181 @@
+use std::ops::Range;
+use crate::{prelude::*, ox, VertexArray, Program, OwlError, DrawMode, DrawIndirectBuffer};
+
+/// The parameters of one non-indexed draw, as read by the GPU from a [`DrawIndirectBuffer`]
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct DrawArraysIndirectCommand {
+    /// The number of vertices to draw
+    pub count: u32,
+    /// The number of instances to draw, 0 skips the draw
+    pub instance_count: u32,
+    /// The index of the first vertex
+    pub first: u32,
+    /// The instance that instanced inputs start from
+    pub base_instance: u32,
+}
+/// The parameters of one indexed draw, as read by the GPU from a [`DrawIndirectBuffer`]
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct DrawElementsIndirectCommand {
+    /// The number of elements to draw
+    pub count: u32,
+    /// The number of instances to draw, 0 skips the draw
+    pub instance_count: u32,
+    /// The index of the first element
+    pub first_index: u32,
+    /// A value added to each element, before indexing the vertices
+    pub base_vertex: i32,
+    /// The instance that instanced inputs start from
+    pub base_instance: u32,
+}
+impl ToByteVec for DrawArraysIndirectCommand {
+    const STRIDE: Option<Bytes> = Some(Bytes(std::mem::size_of::<Self>()));
+    fn to_byte_vec(self) -> Vec<u8> {
+        [self.count, self.instance_count, self.first, self.base_instance].to_byte_vec()
+    }
+    fn stride(&self) -> Bytes {
+        Bytes(std::mem::size_of::<Self>())
+    }
+}
+impl ToByteVec for DrawElementsIndirectCommand {
+    const STRIDE: Option<Bytes> = Some(Bytes(std::mem::size_of::<Self>()));
+    fn to_byte_vec(self) -> Vec<u8> {
+        let mut bytes = [self.count, self.instance_count, self.first_index].to_byte_vec();
+        bytes.extend(self.base_vertex.to_byte_vec());
+        bytes.extend(self.base_instance.to_byte_vec());
+        bytes
+    }
+    fn stride(&self) -> Bytes {
+        Bytes(std::mem::size_of::<Self>())
+    }
+}
+impl FromByteVec for DrawArraysIndirectCommand {
+    const BYTE_SIZE: usize = std::mem::size_of::<Self>();
+    fn from_byte_slice(bytes: &[u8]) -> Self {
+        let [count, instance_count, first, base_instance] = <[u32; 4]>::from_byte_slice(bytes);
+        Self { count, instance_count, first, base_instance }
+    }
+}
+impl FromByteVec for DrawElementsIndirectCommand {
+    const BYTE_SIZE: usize = std::mem::size_of::<Self>();
+    fn from_byte_slice(bytes: &[u8]) -> Self {
+        let [count, instance_count, first_index] = <[u32; 3]>::from_byte_slice(bytes);
+        Self {
+            count, instance_count, first_index,
+            base_vertex: i32::from_byte_slice(&bytes[12..]),
+            base_instance: u32::from_byte_slice(&bytes[16..]),
+        }
+    }
+}
+// SAFETY: repr(C), with 4 byte fields and so no padding, written in order by to_byte_vec
+unsafe impl Pod for DrawArraysIndirectCommand {}
+// SAFETY: repr(C), with 4 byte fields and so no padding, written in order by to_byte_vec
+unsafe impl Pod for DrawElementsIndirectCommand {}
+
+mod sealed {
+    pub trait Sealed {}
+    impl Sealed for super::DrawArraysIndirectCommand {}
+    impl Sealed for super::DrawElementsIndirectCommand {}
+}
+/// The records a [`DrawIndirectBuffer`] can hold, which determine whether its draws are indexed.
+/// It is sealed, as OpenGL reads no others.
+pub trait IndirectCommand: sealed::Sealed + Pod + FromByteVec {
+    /// Whether the draws index vertices through the vertex array's element buffer
+    const INDEXED: bool;
+}
+impl IndirectCommand for DrawArraysIndirectCommand {
+    const INDEXED: bool = false;
+}
+impl IndirectCommand for DrawElementsIndirectCommand {
+    const INDEXED: bool = true;
+}
+
+/// Draw with the command at `index` in `commands`, whose parameters are read by the GPU
+///
+/// # Errors
+///
+/// This function will return an error if `index` is out of bounds, the commands are indexed but the
+/// vertex array has no element buffer, any buffers used are being mapped, or an incompatible
+/// geometry shader is used within `shader_program`.
+///
+/// # Notes
+///
+/// The commands are not checked against the sizes of the vertex array's buffers,
+/// as they may have been written by the GPU.
+pub fn draw_indirect<E: IndexElement, C: IndirectCommand>(mode: DrawMode, vertex_array: &VertexArray<E>,
+    shader_program: &Program, commands: &DrawIndirectBuffer<C>, index: usize) -> Result<(),OwlError> {
+    if index >= commands.len() {
+        return Err(OwlError::custom("index >= number of commands"))
+            .with_context("drawing indirectly");
+    }
+    prepare(vertex_array, shader_program, commands).with_context("drawing indirectly")?;
+    let offset = index * std::mem::size_of::<C>();
+    let drawn = if C::INDEXED {
+        ox::draw_elements_indirect(mode, E::INDEX_TYPE, offset)
+    } else {
+        ox::draw_arrays_indirect(mode, offset)
+    };
+    DrawIndirectBuffer::<C>::unbind();
+    drawn.map_err(|e| map_draw_error(e).with_context("drawing indirectly"))
+}
+
+/// Draw with each of the commands in `range`, in a single call
+///
+/// # Errors
+///
+/// This function will return an error if `range` exceeds the commands, the commands are indexed but the
+/// vertex array has no element buffer, any buffers used are being mapped, or an incompatible
+/// geometry shader is used within `shader_program`.
+///
+/// # Notes
+///
+/// The commands are not checked against the sizes of the vertex array's buffers,
+/// as they may have been written by the GPU.
+pub fn multi_draw_indirect<E: IndexElement, C: IndirectCommand>(mode: DrawMode, vertex_array: &VertexArray<E>,
+    shader_program: &Program, commands: &DrawIndirectBuffer<C>, range: Range<usize>) -> Result<(),OwlError> {
+    if range.start > range.end {
+        return Err(OwlError::custom("range start > range end"))
+            .with_context("multi-drawing indirectly");
+    }
+    if range.end > commands.len() {
+        return Err(OwlError::custom("range end > number of commands"))
+            .with_context("multi-drawing indirectly");
+    }
+    prepare(vertex_array, shader_program, commands).with_context("multi-drawing indirectly")?;
+    let offset = range.start * std::mem::size_of::<C>();
+    // commands are tightly packed, so a stride of 0 suffices
+    let drawn = if C::INDEXED {
+        ox::multi_draw_elements_indirect(mode, E::INDEX_TYPE, offset, range.len(), 0)
+    } else {
+        ox::multi_draw_arrays_indirect(mode, offset, range.len(), 0)
+    };
+    DrawIndirectBuffer::<C>::unbind();
+    drawn.map_err(|e| map_draw_error(e).with_context("multi-drawing indirectly"))
+}
+
+/// Use the program, and bind the vertex array and commands, checking they are compatible
+///
+/// # Errors
+/// This function will return an error if the commands are indexed, but the vertex array has no element buffer,
+/// any of its vertex buffers are being mapped, or the program cannot be used.
+fn prepare<E: IndexElement, C: IndirectCommand>(vertex_array: &VertexArray<E>, shader_program: &Program,
+    commands: &DrawIndirectBuffer<C>) -> Result<(),OwlError> {
+    if C::INDEXED && vertex_array.elements.is_none() {
+        return Err(OwlError::custom("indexed commands need the vertex array to have an element buffer"));
+    }
+    vertex_array.check_unmapped()?;
+    shader_program.use_self()?;
+    vertex_array.bind();
+    commands.bind();
+    Ok(())
+}
+
+fn map_draw_error(e: ox::OxError) -> OwlError {
+    match e {
+        ox::OxError::BaseError(crate::OriginalError::InvalidOperation) =>
+            e.with_message("either one of the buffers used is being mapped, or \
+                the geometry shader's input primitive is incompatible with the draw mode"),
+        _ => e.with_message("no other errors should be produced")
+    }
+}
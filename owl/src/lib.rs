@@ -16,12 +16,26 @@ mod errors;
 pub use errors::*;
 mod buffers;
 pub use buffers::*;
+mod mapping;
+pub use mapping::*;
+mod stream_buffers;
+pub use stream_buffers::*;
 mod vertex_arrays;
 pub use vertex_arrays::*;
+mod vertex_data;
+pub use vertex_data::*;
+#[cfg(any(feature = "glam", feature = "nalgebra", feature = "cgmath", feature = "mint"))]
+mod math;
 mod shaders;
 pub use shaders::*;
 mod mesh;
 pub use mesh::*;
+mod arena;
+pub use arena::*;
+mod indirect;
+pub use indirect::*;
+mod textures;
+pub use textures::*;
 pub mod screen;
 pub use screen::Colour;
 
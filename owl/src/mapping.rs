@@ -0,0 +1,183 @@
+use std::ops::{Deref, DerefMut, Range};
+use std::ptr::NonNull;
+use bitflags::bitflags;
+use crate::ox;
+use crate::traits::Pod;
+use crate::{OwlError, ToOwlError};
+use crate::buffers::BufferName;
+
+bitflags! (
+    /// Hints for a writable mapping, which may allow the driver to avoid stalling
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    pub struct MapFlags: u8 {
+        /// Allow the mapped range to be read, as well as written.
+        /// May not be combined with any of the other flags.
+        const Read = 1;
+        /// The previous contents of the mapped range may be discarded
+        const InvalidateRange = 1 << 1;
+        /// The previous contents of the entire buffer may be discarded
+        const InvalidateBuffer = 1 << 2;
+        /// Do not wait for pending draws using the buffer to finish, before mapping it.
+        /// Overwriting data they still read from is then up to you to avoid.
+        const Unsynchronized = 1 << 3;
+    }
+);
+impl From<MapFlags> for ox::MapAccessFlags {
+    fn from(value: MapFlags) -> Self {
+        let mut access = Self::Write;
+        if value.contains(MapFlags::Read) {
+            access |= Self::Read;
+        }
+        if value.contains(MapFlags::InvalidateRange) {
+            access |= Self::InvalidateRange;
+        }
+        if value.contains(MapFlags::InvalidateBuffer) {
+            access |= Self::InvalidateBuffer;
+        }
+        if value.contains(MapFlags::Unsynchronized) {
+            access |= Self::Unsynchronized;
+        }
+        access
+    }
+}
+
+/// A range of elements of a buffer, mapped into client memory until it is dropped
+#[derive(Debug)]
+struct Mapping<'a, T: Pod> {
+    target: ox::BufferType,
+    buffer: &'a BufferName,
+    pointer: NonNull<T>,
+    length: usize,
+}
+impl<'a, T: Pod> Mapping<'a, T> {
+    /// `range` is measured in elements
+    ///
+    /// # Errors
+    /// This function will return an error if the range is empty or exceeds the buffer,
+    /// or the access flags are incompatible.
+    fn new(target: ox::BufferType, buffer: &'a BufferName, range: Range<usize>,
+        access: ox::MapAccessFlags) -> Result<Self, OwlError> {
+        ox::bind_buffer(target, Some(&**buffer))
+            .expect("buffer should not be deleted yet");
+        let size = ox::get_buffer_size(target)
+            .expect("buffer was just bound");
+        let element_size = std::mem::size_of::<T>();
+        if range.is_empty() {
+            return Err(OwlError::custom("cannot map an empty range"));
+        }
+        if range.end * element_size > size {
+            return Err(OwlError::custom("range exceeds buffer size"));
+        }
+        let pointer = ox::map_buffer_range(target, range.start * element_size,
+            range.len() * element_size, access).map_err(|e| {
+            match e {
+                ox::OxError::BaseError(crate::OriginalError::InvalidOperation) => {
+                    e.with_message("either the buffer is already (perhaps persistently) mapped, Read was combined with \
+                        another flag, or the buffer's immutable storage does not allow the access")
+                },
+                ox::OxError::BaseError(crate::OriginalError::OutOfMemory) => {
+                    e.with_message("out of memory")
+                },
+                _ => e.with_message("no other errors should be produced")
+            }
+        })?;
+        buffer.set_mapped(true);
+        Ok(Self { target, buffer, pointer: pointer.cast(), length: range.len() })
+    }
+    const fn as_slice(&self) -> &[T] {
+        // SAFETY: the pointer is valid for `length` elements until the buffer is unmapped, on drop.
+        // It is aligned, as GL aligns mappings to GL_MIN_MAP_BUFFER_ALIGNMENT (at least 64)
+        // before the offset, which is a multiple of the size (and so alignment) of T.
+        // Any bit pattern is a valid T, as it is Pod.
+        unsafe {
+            std::slice::from_raw_parts(self.pointer.as_ptr(), self.length)
+        }
+    }
+    const fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: as in as_slice; the guard is the only way to reach the mapping,
+        // so this is the only reference to it
+        unsafe {
+            std::slice::from_raw_parts_mut(self.pointer.as_ptr(), self.length)
+        }
+    }
+}
+impl<T: Pod> Drop for Mapping<'_, T> {
+    fn drop(&mut self) {
+        ox::bind_buffer(self.target, Some(&**self.buffer))
+            .expect("buffer should not be deleted yet");
+        let status = ox::unmap_buffer(self.target)
+            .expect("buffer was just bound, and is mapped");
+        self.buffer.set_mapped(false);
+        if status == ox::UnmapStatus::Corrupted {
+            log::warn!("the contents of buffer {:?} were corrupted while mapped, and must be reuploaded",
+                self.buffer);
+        }
+    }
+}
+
+/// A read-only view of a mapped buffer, which is unmapped when this is dropped.
+///
+/// It mutably borrows the buffer, so that it cannot be used in the meantime; vertex arrays
+/// reading from it return an error if drawn with instead, as they do not borrow it.
+#[derive(Debug)]
+pub struct MappedBuffer<'a, T: Pod> {
+    mapping: Mapping<'a, T>,
+    _buffer: std::marker::PhantomData<&'a mut T>,
+}
+impl<'a, T: Pod> MappedBuffer<'a, T> {
+    /// `range` is measured in elements
+    ///
+    /// # Errors
+    /// This function will return an error if the range is empty or exceeds the buffer.
+    pub(crate) fn new(target: ox::BufferType, buffer: &'a BufferName, range: Range<usize>) -> Result<Self, OwlError> {
+        Ok(Self {
+            mapping: Mapping::new(target, buffer, range, ox::MapAccessFlags::Read)?,
+            _buffer: std::marker::PhantomData,
+        })
+    }
+}
+impl<T: Pod> Deref for MappedBuffer<'_, T> {
+    type Target = [T];
+    fn deref(&self) -> &[T] {
+        self.mapping.as_slice()
+    }
+}
+
+/// A writable view of a mapped buffer, which is unmapped when this is dropped.
+///
+/// It mutably borrows the buffer, so that it cannot be used in the meantime; vertex arrays
+/// reading from it return an error if drawn with instead, as they do not borrow it.
+///
+/// # Notes
+/// Unless it was mapped with [`MapFlags::Read`], reading from it is allowed, but its contents
+/// are undefined, and likely to be slow to access.
+#[derive(Debug)]
+pub struct MappedBufferMut<'a, T: Pod> {
+    mapping: Mapping<'a, T>,
+    _buffer: std::marker::PhantomData<&'a mut T>,
+}
+impl<'a, T: Pod> MappedBufferMut<'a, T> {
+    /// `range` is measured in elements
+    ///
+    /// # Errors
+    /// This function will return an error if the range is empty or exceeds the buffer,
+    /// or the flags are incompatible.
+    pub(crate) fn new(target: ox::BufferType, buffer: &'a BufferName, range: Range<usize>,
+        flags: MapFlags) -> Result<Self, OwlError> {
+        Ok(Self {
+            mapping: Mapping::new(target, buffer, range, flags.into())?,
+            _buffer: std::marker::PhantomData,
+        })
+    }
+}
+impl<T: Pod> Deref for MappedBufferMut<'_, T> {
+    type Target = [T];
+    fn deref(&self) -> &[T] {
+        self.mapping.as_slice()
+    }
+}
+impl<T: Pod> DerefMut for MappedBufferMut<'_, T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.mapping.as_mut_slice()
+    }
+}
@@ -0,0 +1,159 @@
+//! Implementations for the vectors and matrices of math libraries, each behind a feature of the
+//! same name. They are laid out and input as the arrays they convert to, vectors as `[T; N]`,
+//! and matrices as `[[T; rows]; columns]`, in column-major order.
+
+use crate::prelude::*;
+
+/// The byte and layout traits of `$math`, through `$array`, by the conversions `$to` and `$from`
+macro_rules! impl_math_type {
+    ($math:ty => $array:ty, $to:expr, $from:expr) => {
+        impl ToByteVec for $math {
+            const STRIDE: Option<Bytes> = <$array as ToByteVec>::STRIDE;
+            fn to_byte_vec(self) -> Vec<u8> {
+                ($to)(self).to_byte_vec()
+            }
+            fn stride(&self) -> Bytes {
+                Bytes(std::mem::size_of::<$array>())
+            }
+        }
+        impl FromByteVec for $math {
+            const BYTE_SIZE: usize = <$array as FromByteVec>::BYTE_SIZE;
+            fn from_byte_slice(bytes: &[u8]) -> Self {
+                ($from)(<$array>::from_byte_slice(bytes))
+            }
+        }
+        const _: () = assert!(std::mem::size_of::<$math>() == std::mem::size_of::<$array>(),
+            "math types must have no padding");
+        // SAFETY: repr(C) (or SIMD) over its components in column-major order, as the array is,
+        // and the same size, so there is no padding
+        unsafe impl Pod for $math {}
+        impl Std140 for $math {
+            const STD140_ALIGNMENT: usize = <$array as Std140>::STD140_ALIGNMENT;
+            const STD140_SIZE: usize = <$array as Std140>::STD140_SIZE;
+            fn write_std140(&self, bytes: &mut Vec<u8>) {
+                ($to)(*self).write_std140(bytes);
+            }
+            fn read_std140(bytes: &[u8]) -> Self {
+                ($from)(<$array>::read_std140(bytes))
+            }
+        }
+        impl Std430 for $math {
+            const STD430_ALIGNMENT: usize = <$array as Std430>::STD430_ALIGNMENT;
+            const STD430_SIZE: usize = <$array as Std430>::STD430_SIZE;
+            fn write_std430(&self, bytes: &mut Vec<u8>) {
+                ($to)(*self).write_std430(bytes);
+            }
+            fn read_std430(bytes: &[u8]) -> Self {
+                ($from)(<$array>::read_std430(bytes))
+            }
+            fn glsl_type() -> String {
+                <$array as Std430>::glsl_type()
+            }
+        }
+    };
+    ($math:ty => $array:ty) => {
+        impl_math_type!($math => $array, <$math as Into<$array>>::into, <$math>::from);
+    };
+}
+/// A vector of floats, which is also a vertex input
+macro_rules! impl_vector {
+    ($($vector:ty => $array:ty),*) => {$(
+        impl_math_type!($vector => $array);
+        impl VertexField for $vector {
+            fn float_input(name: String, normalise: bool) -> crate::ThinInputAttribute {
+                <$array as VertexField>::float_input(name, normalise)
+            }
+        }
+    )*};
+}
+/// A vector of doubles, which is also a full precision vertex input
+macro_rules! impl_double_vector {
+    ($($vector:ty => $array:ty),*) => {$(
+        impl_vector!($vector => $array);
+        impl DoubleVertexField for $vector {
+            fn double_input(name: String) -> crate::ThinInputAttribute {
+                <$array as DoubleVertexField>::double_input(name)
+            }
+        }
+    )*};
+}
+/// A vector of integers, which is also an integral vertex input
+macro_rules! impl_integral_vector {
+    ($($vector:ty => $array:ty),*) => {$(
+        impl_vector!($vector => $array);
+        impl IntegralVertexField for $vector {
+            fn integral_input(name: String) -> crate::ThinInputAttribute {
+                <$array as IntegralVertexField>::integral_input(name)
+            }
+        }
+    )*};
+}
+
+#[cfg(feature = "glam")]
+mod glam_impls {
+    use super::{Bytes, DoubleVertexField, FromByteVec, IntegralVertexField, Pod, Std140, Std430, ToByteVec, VertexField};
+    impl_vector!(glam::Vec2 => [f32; 2], glam::Vec3 => [f32; 3], glam::Vec4 => [f32; 4]);
+    impl_double_vector!(glam::DVec2 => [f64; 2], glam::DVec3 => [f64; 3], glam::DVec4 => [f64; 4]);
+    impl_integral_vector!(glam::IVec2 => [i32; 2], glam::IVec3 => [i32; 3], glam::IVec4 => [i32; 4],
+        glam::UVec2 => [u32; 2], glam::UVec3 => [u32; 3], glam::UVec4 => [u32; 4]);
+    impl_math_type!(glam::Mat2 => [[f32; 2]; 2], |m: glam::Mat2| m.to_cols_array_2d(), |a| glam::Mat2::from_cols_array_2d(&a));
+    impl_math_type!(glam::Mat3 => [[f32; 3]; 3], |m: glam::Mat3| m.to_cols_array_2d(), |a| glam::Mat3::from_cols_array_2d(&a));
+    impl_math_type!(glam::Mat4 => [[f32; 4]; 4], |m: glam::Mat4| m.to_cols_array_2d(), |a| glam::Mat4::from_cols_array_2d(&a));
+    impl_math_type!(glam::DMat2 => [[f64; 2]; 2], |m: glam::DMat2| m.to_cols_array_2d(), |a| glam::DMat2::from_cols_array_2d(&a));
+    impl_math_type!(glam::DMat3 => [[f64; 3]; 3], |m: glam::DMat3| m.to_cols_array_2d(), |a| glam::DMat3::from_cols_array_2d(&a));
+    impl_math_type!(glam::DMat4 => [[f64; 4]; 4], |m: glam::DMat4| m.to_cols_array_2d(), |a| glam::DMat4::from_cols_array_2d(&a));
+}
+
+#[cfg(feature = "nalgebra")]
+mod nalgebra_impls {
+    use super::{Bytes, DoubleVertexField, FromByteVec, IntegralVertexField, Pod, Std140, Std430, ToByteVec, VertexField};
+    impl_vector!(nalgebra::Vector2<f32> => [f32; 2], nalgebra::Vector3<f32> => [f32; 3],
+        nalgebra::Vector4<f32> => [f32; 4]);
+    impl_double_vector!(nalgebra::Vector2<f64> => [f64; 2], nalgebra::Vector3<f64> => [f64; 3],
+        nalgebra::Vector4<f64> => [f64; 4]);
+    impl_integral_vector!(nalgebra::Vector2<i32> => [i32; 2], nalgebra::Vector3<i32> => [i32; 3],
+        nalgebra::Vector4<i32> => [i32; 4], nalgebra::Vector2<u32> => [u32; 2],
+        nalgebra::Vector3<u32> => [u32; 3], nalgebra::Vector4<u32> => [u32; 4]);
+    impl_math_type!(nalgebra::Matrix2<f32> => [[f32; 2]; 2]);
+    impl_math_type!(nalgebra::Matrix3<f32> => [[f32; 3]; 3]);
+    impl_math_type!(nalgebra::Matrix4<f32> => [[f32; 4]; 4]);
+    impl_math_type!(nalgebra::Matrix2<f64> => [[f64; 2]; 2]);
+    impl_math_type!(nalgebra::Matrix3<f64> => [[f64; 3]; 3]);
+    impl_math_type!(nalgebra::Matrix4<f64> => [[f64; 4]; 4]);
+}
+
+#[cfg(feature = "cgmath")]
+mod cgmath_impls {
+    use super::{Bytes, DoubleVertexField, FromByteVec, IntegralVertexField, Pod, Std140, Std430, ToByteVec, VertexField};
+    impl_vector!(cgmath::Vector2<f32> => [f32; 2], cgmath::Vector3<f32> => [f32; 3],
+        cgmath::Vector4<f32> => [f32; 4]);
+    impl_double_vector!(cgmath::Vector2<f64> => [f64; 2], cgmath::Vector3<f64> => [f64; 3],
+        cgmath::Vector4<f64> => [f64; 4]);
+    impl_integral_vector!(cgmath::Vector2<i32> => [i32; 2], cgmath::Vector3<i32> => [i32; 3],
+        cgmath::Vector4<i32> => [i32; 4], cgmath::Vector2<u32> => [u32; 2],
+        cgmath::Vector3<u32> => [u32; 3], cgmath::Vector4<u32> => [u32; 4]);
+    impl_math_type!(cgmath::Matrix2<f32> => [[f32; 2]; 2]);
+    impl_math_type!(cgmath::Matrix3<f32> => [[f32; 3]; 3]);
+    impl_math_type!(cgmath::Matrix4<f32> => [[f32; 4]; 4]);
+    impl_math_type!(cgmath::Matrix2<f64> => [[f64; 2]; 2]);
+    impl_math_type!(cgmath::Matrix3<f64> => [[f64; 3]; 3]);
+    impl_math_type!(cgmath::Matrix4<f64> => [[f64; 4]; 4]);
+}
+
+#[cfg(feature = "mint")]
+mod mint_impls {
+    use super::{Bytes, DoubleVertexField, FromByteVec, IntegralVertexField, Pod, Std140, Std430, ToByteVec, VertexField};
+    impl_vector!(mint::Vector2<f32> => [f32; 2], mint::Vector3<f32> => [f32; 3],
+        mint::Vector4<f32> => [f32; 4]);
+    impl_double_vector!(mint::Vector2<f64> => [f64; 2], mint::Vector3<f64> => [f64; 3],
+        mint::Vector4<f64> => [f64; 4]);
+    impl_integral_vector!(mint::Vector2<i32> => [i32; 2], mint::Vector3<i32> => [i32; 3],
+        mint::Vector4<i32> => [i32; 4], mint::Vector2<u32> => [u32; 2],
+        mint::Vector3<u32> => [u32; 3], mint::Vector4<u32> => [u32; 4]);
+    impl_math_type!(mint::ColumnMatrix2<f32> => [[f32; 2]; 2]);
+    impl_math_type!(mint::ColumnMatrix3<f32> => [[f32; 3]; 3]);
+    impl_math_type!(mint::ColumnMatrix4<f32> => [[f32; 4]; 4]);
+    impl_math_type!(mint::ColumnMatrix2<f64> => [[f64; 2]; 2]);
+    impl_math_type!(mint::ColumnMatrix3<f64> => [[f64; 3]; 3]);
+    impl_math_type!(mint::ColumnMatrix4<f64> => [[f64; 4]; 4]);
+}
@@ -1,45 +1,86 @@
 use crate::{prelude::*, ox, VertexArray, Program, OwlError};
 
-pub struct Mesh<'a,E: ToByteVec> {
+pub struct Mesh<'a,E: IndexElement> {
     pub start: usize,
     pub count: usize,
+    /// The vertex the mesh's indices count from, e.g. for meshes sharing a [`crate::BufferArena`]
+    pub base_vertex: usize,
     pub vertex_array: &'a VertexArray<E>
 }
 
 pub use ox::DrawMode;
-impl<'a, E: ToByteVec> Mesh<'a, E> {
+impl<'a, E: IndexElement> Mesh<'a, E> {
     /// # Errors
     ///
     /// This function will return an error if any buffers used for data are being mapped,
-    /// or an incompatible geometry shader is used within `shader_program`.
+    /// an incompatible geometry shader is used within `shader_program`,
+    /// or more elements are drawn than the vertex array's element buffer holds.
+    ///
+    /// # Notes
+    ///
+    /// The vertices drawn, whether directly or through the indices, are not checked against the sizes
+    /// of the vertex array's buffers, as that depends on the layout of every input.
     pub fn draw(&self, mode: DrawMode, shader_program: &Program) -> Result<(),OwlError> {
-        shader_program.use_self().with_context("drawing mesh")?;
+        self.draw_instances(mode, shader_program, None).with_context("drawing mesh")
+    }
+    /// Draw `instance_count` copies of the mesh in one call, with instanced inputs starting from
+    /// `base_instance`, see [`VertexArray::with_instanced_input`]
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if any buffers used for data are being mapped,
+    /// an incompatible geometry shader is used within `shader_program`,
+    /// or more elements are drawn than the vertex array's element buffer holds.
+    ///
+    /// # Notes
+    ///
+    /// As [`Mesh::draw`], the vertices and instances drawn are not checked against the sizes of the
+    /// vertex array's buffers.
+    pub fn draw_instanced(&self, mode: DrawMode, shader_program: &Program, instance_count: usize,
+        base_instance: u32) -> Result<(),OwlError> {
+        self.draw_instances(mode, shader_program, Some((instance_count, base_instance)))
+            .with_context("drawing instanced mesh")
+    }
+    /// Draw the mesh once, or `instance_count` times from `base_instance` if `instances` is given
+    ///
+    /// # Errors
+    ///
+    /// As [`Mesh::draw`]
+    fn draw_instances(&self, mode: DrawMode, shader_program: &Program, instances: Option<(usize, u32)>)
+        -> Result<(),OwlError> {
+        self.vertex_array.check_unmapped()?;
+        shader_program.use_self()?;
         self.vertex_array.bind();
-        match self.vertex_array.elements {
-            Some(ref e) => {
-                ox::draw_elements(mode, self.count, e.inner_type, self.start).map_err(|e|
-                    match e {
-                        ox::OxError::BaseError(crate::OriginalError::InvalidOperation) => 
-                            e.with_message("either one of the buffers used is being mapped, or
-                                the geometry shader's input primitive is incompatible with the
-                                draw mode"),
-                        _ => e.with_message("no other errors should be produced")
-                    }
-                    .with_context("drawing mesh, element draw failed")
-                )
-            },
-            None => {
-                ox::draw_arrays(mode, self.start, self.count).map_err(|e|
-                    match e {
-                        ox::OxError::BaseError(crate::OriginalError::InvalidOperation) => 
-                            e.with_message("either one of the buffers used is being mapped, or
-                                the geometry shader's input primitive is incompatible with the
-                                draw mode"),
-                        _ => e.with_message("no other errors should be produced")
-                    }
-                    .with_context("drawing mesh, element draw failed")
-                )
-            },
-        }
+        let drawn = if let Some(ref elements) = self.vertex_array.elements {
+            if self.start.checked_add(self.count).is_none_or(|end| end > elements.len()) {
+                return Err(OwlError::custom("start + count > number of elements"));
+            }
+            let base_vertex = i32::try_from(self.base_vertex)
+                .map_err(|_| OwlError::custom("base vertex > i32::MAX"))?;
+            // the offset into the element buffer is in bytes
+            let offset = self.start * std::mem::size_of::<E>();
+            match instances {
+                Some((instance_count, base_instance)) => ox::draw_elements_instanced_base_vertex_base_instance(
+                    mode, self.count, E::INDEX_TYPE, offset, instance_count, base_vertex, base_instance),
+                None if base_vertex == 0 => ox::draw_elements(mode, self.count, E::INDEX_TYPE, offset),
+                None => ox::draw_elements_base_vertex(mode, self.count, E::INDEX_TYPE, offset, base_vertex),
+            }
+        } else {
+            let first = self.base_vertex.checked_add(self.start)
+                .ok_or_else(|| OwlError::custom("base vertex + start overflows"))?;
+            match instances {
+                Some((instance_count, base_instance)) => ox::draw_arrays_instanced_base_instance(mode,
+                    first, self.count, instance_count, base_instance),
+                None => ox::draw_arrays(mode, first, self.count),
+            }
+        };
+        drawn.map_err(|e|
+            match e {
+                ox::OxError::BaseError(crate::OriginalError::InvalidOperation) =>
+                    e.with_message("either one of the buffers used is being mapped, or \
+                        the geometry shader's input primitive is incompatible with the draw mode"),
+                _ => e.with_message("no other errors should be produced")
+            }
+        )
     }
 }
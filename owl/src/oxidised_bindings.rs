@@ -56,7 +56,8 @@ fn last_error_as_result() -> Result<(),OxError> {
 //
 // Buffers
 //
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// The name of a buffer, which is unique: not Clone, so that it is only deleted by its owner
+#[derive(Debug, PartialEq, Eq)]
 pub struct Buffer(u32);
 
 pub fn gen_buffers(count: usize) -> Vec<Buffer> {
@@ -77,7 +78,7 @@ pub fn delete_buffers(buffers: Vec<Buffer>) {
     safe_bindings::DeleteBuffers(buffer_ids.as_slice());
     log::trace!("deleted {} buffers: {:?}", buffer_ids.len(), buffer_ids);
 }
-pub fn delete_buffer(buffer: Buffer) {
+pub fn delete_buffer(buffer: &Buffer) {
     safe_bindings::DeleteBuffer(buffer.0);
     log::trace!("deleted buffer: {}", buffer.0);
 }
@@ -86,13 +87,22 @@ pub use safe_bindings::BufferType;
 /// Bind target 0 (no bound buffer) if provided "None"
 /// # Errors
 /// Invalid Value: buffer was deleted
-pub fn bind_buffer(target: BufferType, buffer: Option<Buffer>) -> Result<(),OxError> {
+pub fn bind_buffer(target: BufferType, buffer: Option<&Buffer>) -> Result<(),OxError> {
     log::trace!("binding buffer: {buffer:?} to {target:?}");
     safe_bindings::BindBuffer(target, buffer.map_or(0, |b| b.0));
     last_error_as_result()
 }
+pub use safe_bindings::IndexedBufferType;
+/// Bind target 0 (no bound buffer) at `index` if provided "None"
+/// # Errors
+/// `GL_INVALID_VALUE`: index >= the number of binding points for target, buffer was deleted
+pub fn bind_buffer_base(target: IndexedBufferType, index: u32, buffer: Option<&Buffer>) -> Result<(),OxError> {
+    log::trace!("binding buffer: {buffer:?} to {target:?} at index {index}");
+    safe_bindings::BindBufferBase(target, index, buffer.map_or(0, |b| b.0));
+    last_error_as_result()
+}
 pub use safe_bindings::BufferUsage;
-use crate::traits::ToByteVec;
+use crate::traits::{ToByteVec, Pod};
 /// # Errors
 /// `GL_INVALID_OPERATON`: `GL_BUFFER_IMMUTABLE_STORAGE` flag of target set to `GL_TRUE`, no buffer bound
 /// `GL_OUT_OF_MEMORY`
@@ -103,6 +113,42 @@ pub fn buffer_data<T>(target: BufferType, data: Vec<T>, usage: BufferUsage) -> R
     last_error_as_result()
 }
 
+/// Uploads `data` as it is laid out in memory, without copying it first
+///
+/// # Errors
+/// `GL_INVALID_OPERATON`: `GL_BUFFER_IMMUTABLE_STORAGE` flag of target set to `GL_TRUE`, no buffer bound
+/// `GL_OUT_OF_MEMORY`
+pub fn buffer_data_slice<T>(target: BufferType, data: &[T], usage: BufferUsage) -> Result<(),OxError>
+    where T: Pod {
+    log::trace!("buffering data of length {} to {target:?} for use {usage:?}", data.len());
+    safe_bindings::BufferData(target, data, usage);
+    last_error_as_result()
+}
+/// Uploads `subdata` as it is laid out in memory, without copying it first
+///
+/// # Errors
+/// `GL_INVALID_OPERATON`: zero is bound to target, target is being mapped
+/// `GL_INVALID_VALUE`: offset + size > buffer size
+///
+/// # Notes
+/// offset in multiples of T
+pub fn buffer_subdata_slice<T>(target: BufferType, subdata: &[T], offset: usize) -> Result<(),OxError>
+    where T: Pod {
+    log::trace!("buffering subdata of length {} to {target:?} at offset {offset}", subdata.len());
+    safe_bindings::BufferSubData(target, subdata, offset * std::mem::size_of::<T>());
+    last_error_as_result()
+}
+/// size in bytes, the contents of the buffer are undefined
+///
+/// # Errors
+/// `GL_INVALID_OPERATON`: `GL_BUFFER_IMMUTABLE_STORAGE` flag of target set to `GL_TRUE`, no buffer bound
+/// `GL_OUT_OF_MEMORY`
+pub fn buffer_data_uninitialised(target: BufferType, size: usize, usage: BufferUsage) -> Result<(),OxError> {
+    log::trace!("allocating {size} bytes to {target:?} for use {usage:?}");
+    safe_bindings::BufferDataUninitialised(target, size, usage);
+    last_error_as_result()
+}
+
 /// # Errors
 /// `GL_INVALID_OPERATON`: zero is bound to target, target is being mapped
 /// `GL_INVALID_VALUE`: offset + size > buffer size
@@ -112,16 +158,118 @@ pub fn buffer_data<T>(target: BufferType, data: Vec<T>, usage: BufferUsage) -> R
 pub fn buffer_subdata<T>(target: BufferType, subdata: Vec<T>, offset: usize) -> Result<(),OxError>
     where T: ToByteVec {
     log::trace!("buffering subdata of length {} to {target:?} at offset {offset}", subdata.len());
+    let stride = T::STRIDE.map_or_else(|| subdata.first().map_or(0, |datum| datum.stride().0), usize::from);
     let byte_vec = subdata.to_byte_vec();
-    safe_bindings::BufferSubData(target, byte_vec.as_slice(), offset * std::mem::size_of_val(&byte_vec));
+    safe_bindings::BufferSubData(target, byte_vec.as_slice(), offset * stride);
+    last_error_as_result()
+}
+/// offset and length in bytes
+///
+/// # Errors
+/// `GL_INVALID_OPERATON`: zero is bound to target, target is being mapped
+/// `GL_INVALID_VALUE`: offset + length > buffer size
+pub fn get_buffer_sub_data(target: BufferType, offset: usize, length: usize) -> Result<Vec<u8>,OxError> {
+    log::trace!("reading {length} bytes from {target:?} at offset {offset}");
+    let mut data = vec![0; length];
+    safe_bindings::GetBufferSubData(target, offset, data.as_mut_slice());
+    last_error_as_result()?;
+    Ok(data)
+}
+/// offsets and size in bytes
+///
+/// # Errors
+/// `GL_INVALID_OPERATON`: zero is bound to either target, either buffer is being mapped
+/// `GL_INVALID_VALUE`: either range exceeds its buffer, the ranges overlap within the same buffer
+pub fn copy_buffer_sub_data(read_target: BufferType, write_target: BufferType, read_offset: usize,
+    write_offset: usize, size: usize) -> Result<(),OxError> {
+    log::trace!("copying {size} bytes from {read_target:?} at offset {read_offset} to {write_target:?} at offset {write_offset}");
+    safe_bindings::CopyBufferSubData(read_target, write_target, read_offset, write_offset, size);
     last_error_as_result()
 }
+pub use safe_bindings::BufferStorageFlags;
+/// # Errors
+/// `GL_INVALID_OPERATON`: `GL_BUFFER_IMMUTABLE_STORAGE` flag of target set to `GL_TRUE`, no buffer bound
+/// `GL_INVALID_VALUE`: data is empty, `MapPersistent` without `MapRead` or `MapWrite`,
+///                     `MapCoherent` without `MapPersistent`
+/// `GL_OUT_OF_MEMORY`
+pub fn buffer_storage<T>(target: BufferType, data: &[T], flags: BufferStorageFlags) -> Result<(),OxError>
+    where T: Pod {
+    log::trace!("allocating immutable storage for data of length {} to {target:?} with flags {flags:?}", data.len());
+    safe_bindings::BufferStorage(target, data, flags);
+    last_error_as_result()
+}
+/// size in bytes, the contents of the buffer are undefined
+///
+/// # Errors
+/// As for [`buffer_storage`], where size == 0 rather than data being empty
+pub fn buffer_storage_uninitialised(target: BufferType, size: usize, flags: BufferStorageFlags) -> Result<(),OxError> {
+    log::trace!("allocating {size} bytes of immutable storage to {target:?} with flags {flags:?}");
+    safe_bindings::BufferStorageUninitialised(target, size, flags);
+    last_error_as_result()
+}
+pub use safe_bindings::MapAccessFlags;
+/// offset and length are in bytes
+///
+/// # Errors
+/// `GL_INVALID_VALUE`: length == 0, offset + length > buffer size
+/// `GL_INVALID_OPERATON`: zero is bound to target, buffer is already mapped,
+///                        invalid combination of access flags
+/// `GL_OUT_OF_MEMORY`
+pub fn map_buffer_range(target: BufferType, offset: usize, length: usize, access: MapAccessFlags)
+    -> Result<std::ptr::NonNull<u8>,OxError> {
+    log::trace!("mapping {length} bytes of {target:?} at offset {offset} with access {access:?}");
+    let pointer = safe_bindings::MapBufferRange(target, offset, length, access);
+    last_error_as_result()?;
+    Ok(std::ptr::NonNull::new(pointer.cast())
+        .expect("glMapBufferRange only returns null if an error is generated"))
+}
+/// offset and length are in bytes, relative to the start of the mapped range
+///
+/// # Errors
+/// `GL_INVALID_VALUE`: offset + length > mapped length
+/// `GL_INVALID_OPERATON`: zero is bound to target, buffer is not mapped with `FlushExplicit`
+pub fn flush_mapped_buffer_range(target: BufferType, offset: usize, length: usize) -> Result<(),OxError> {
+    log::trace!("flushing {length} bytes of mapped {target:?} at offset {offset}");
+    safe_bindings::FlushMappedBufferRange(target, offset, length);
+    last_error_as_result()
+}
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum UnmapStatus {
+    Succeeded,
+    Corrupted
+}
+/// # Errors
+/// `GL_INVALID_OPERATON`: zero is bound to target, buffer is not mapped
+pub fn unmap_buffer(target: BufferType) -> Result<UnmapStatus,OxError> {
+    log::trace!("unmapping {target:?}");
+    let succeeded = safe_bindings::UnmapBuffer(target);
+    last_error_as_result()?;
+    if succeeded {
+        Ok(UnmapStatus::Succeeded)
+    } else {
+        Ok(UnmapStatus::Corrupted)
+    }
+}
+/// size in bytes
+///
+/// # Errors
+/// `GL_INVALID_OPERATON`: zero is bound to target
+pub fn get_buffer_size(target: BufferType) -> Result<usize,OxError> {
+    log::trace!("getting size of {target:?}");
+    let mut data = 0;
+    safe_bindings::GetBufferParameteriv(target, safe_bindings::BufferParameter::Size, &mut data);
+    last_error_as_result()?;
+    // CAST: buffer sizes are never negative
+    #[allow(clippy::cast_sign_loss)]
+    Ok(data as usize)
+}
 
 
 //
 // Vertex Array Objects
 //
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// The name of a vertex array, which is unique: not Clone, so that it is only deleted by its owner
+#[derive(Debug, PartialEq, Eq)]
 pub struct VertexArray(u32);
 pub fn gen_vertex_arrays(count: usize) -> Vec<VertexArray> {
     let mut va_ids = vec![0; count];
@@ -141,14 +289,14 @@ pub fn delete_vertex_arrays(vertex_arrays: Vec<VertexArray>) {
     safe_bindings::DeleteVertexArrays(ids.as_slice());
     log::trace!("deleted {} vertex arrays: {:?}", ids.len(), ids);
 }
-pub fn delete_vertex_array(vertex_array: VertexArray) {
+pub fn delete_vertex_array(vertex_array: &VertexArray) {
     safe_bindings::DeleteVertexArray(vertex_array.0);
     log::trace!("deleted vertex array: {}", vertex_array.0);
 }
 
 /// # Errors
 /// `GL_INVALID_VALUE`: `vertex_array` was deleted
-pub fn bind_vertex_array(vertex_array: Option<VertexArray>) -> Result<(),OxError>{
+pub fn bind_vertex_array(vertex_array: Option<&VertexArray>) -> Result<(),OxError>{
     log::trace!("binding vertex array: {vertex_array:?}");
     safe_bindings::BindVertexArray(vertex_array.map_or(0, |va| va.0));
     last_error_as_result()
@@ -163,6 +311,17 @@ pub fn enable_vertex_attrib_array(attribute_index: u8) -> Result<(),OxError> {
     last_error_as_result()
 }
 
+/// A divisor of 0 advances the attribute per vertex, otherwise once per `divisor` instances
+///
+/// # Errors
+/// `GL_INVALID_OPERATON`: no vertex array object is bound
+/// `GL_INVALID_VALUE`: `attribute_index` >= `GL_MAX_VERTEX_ATTRIBS`
+pub fn vertex_attrib_divisor(attribute_index: u8, divisor: u32) -> Result<(),OxError> {
+    log::trace!("setting the divisor of vertex attribute {attribute_index} to {divisor}");
+    safe_bindings::VertexAttribDivisor(attribute_index, divisor);
+    last_error_as_result()
+}
+
 pub use safe_bindings::AttribSize;
 /// Subenum of [`DataType`]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -336,6 +495,53 @@ pub fn vertex_attrib_i_pointer(attribute_index: u8, spec: IntegralVertexFormat,
     last_error_as_result()
 }
 
+/// The number of doubles in each vertex attribute, which are input at full precision
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DoubleVertexFormat {
+    Size1,
+    Size2,
+    Size3,
+    Size4,
+}
+impl DoubleVertexFormat {
+    /// The number of bytes each attribute is read from
+    #[must_use]
+    pub const fn size_bytes(self) -> usize {
+        let count = match self {
+            Self::Size1 => 1,
+            Self::Size2 => 2,
+            Self::Size3 => 3,
+            Self::Size4 => 4,
+        };
+        count * std::mem::size_of::<f64>()
+    }
+    /// The number of consecutive locations each attribute takes: 2 for a `dvec3` or `dvec4`, which
+    /// are twice the size of a `vec4`
+    #[must_use]
+    pub const fn locations(self) -> u8 {
+        match self {
+            Self::Size1 | Self::Size2 => 1,
+            Self::Size3 | Self::Size4 => 2,
+        }
+    }
+}
+
+/// # Errors
+/// `GL_INVALID_VALUE`: index >= `GL_MAX_VERTEX_ATTRIBS`
+/// `GL_INVALID_OPERATON`: array buffer bound to 0, and offset != 0
+pub fn vertex_attrib_l_pointer(attribute_index: u8, spec: DoubleVertexFormat,
+    stride: usize, offset: usize) -> Result<(),OxError> {
+    log::trace!("registering pointer for double vertex attribute {attribute_index}, in format {spec:?}, with stride {stride}, at offset {offset}");
+    let size = match spec {
+        DoubleVertexFormat::Size1 => IntegralAttribSize::One,
+        DoubleVertexFormat::Size2 => IntegralAttribSize::Two,
+        DoubleVertexFormat::Size3 => IntegralAttribSize::Three,
+        DoubleVertexFormat::Size4 => IntegralAttribSize::Four,
+    };
+    safe_bindings::VertexAttribLPointer(attribute_index, size, stride, offset);
+    last_error_as_result()
+}
+
 //
 // get*
 //
@@ -346,6 +552,8 @@ pub enum UIntParameter {
     ArrayBufferBinding,
     ElementBufferBinding,
     MaxComputeShaderStorageBlocks,
+    MaxUniformBufferBindings,
+    MaxCombinedTextureImageUnits,
 }
 #[must_use]
 pub fn get_uint(parameter: UIntParameter) -> u32 {
@@ -355,6 +563,8 @@ pub fn get_uint(parameter: UIntParameter) -> u32 {
         UIntParameter::ArrayBufferBinding => safe_bindings::Parameter::ArrayBufferBinding,
         UIntParameter::ElementBufferBinding => safe_bindings::Parameter::ElementBufferBinding,
         UIntParameter::MaxComputeShaderStorageBlocks => safe_bindings::Parameter::MaxComputeShaderStorageBlocks,
+        UIntParameter::MaxUniformBufferBindings => safe_bindings::Parameter::MaxUniformBufferBindings,
+        UIntParameter::MaxCombinedTextureImageUnits => safe_bindings::Parameter::MaxCombinedTextureImageUnits,
     };
     // SAFETY: only parameters that are single values may be used (constrained by UintParameter),
     // so data must always be of length one.
@@ -390,7 +600,8 @@ impl std::fmt::Display for ShaderError {
 impl std::error::Error for ShaderError {}
 
 pub use safe_bindings::ShaderType;
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// The name of a shader, which is unique: not Clone, so that it is only deleted by its owner
+#[derive(Debug, PartialEq, Eq, Hash)]
 pub struct Shader(u32);
 /// # Errors
 ///
@@ -409,6 +620,7 @@ pub fn create_shader(shader_type: ShaderType) -> Result<Shader,OxError> {
 }
 /// # Errors
 /// `GL_INVALID_VALUE`: shader has been deleted
+#[allow(clippy::needless_pass_by_value)]     // the shader must not be used once deleted
 pub fn delete_shader(shader: Shader) -> Result<(),OxError> {
     log::trace!("deleting shader: {}", shader.0);
     safe_bindings::DeleteShader(shader.0);
@@ -417,7 +629,7 @@ pub fn delete_shader(shader: Shader) -> Result<(),OxError> {
 
 /// # Errors
 /// `GL_INVALID_VALUE`: shader has been deleted
-pub fn shader_source<CS>(shader: Shader, sources: &[CS]) -> Result<(),OxError>
+pub fn shader_source<CS>(shader: &Shader, sources: &[CS]) -> Result<(),OxError>
     where CS: AsRef<std::ffi::CStr> {
     log::trace!("adding source(s) to shader {}, sources: {:?}", shader.0, sources.iter().map(AsRef::as_ref).collect::<Vec<_>>());
     safe_bindings::ShaderSource(shader.0, sources);
@@ -427,7 +639,7 @@ pub fn shader_source<CS>(shader: Shader, sources: &[CS]) -> Result<(),OxError>
 /// # Errors
 /// `GL_INVALID_VALUE`: shader has been deleted
 /// `CompilationFailed`
-pub fn compile_shader(shader: Shader) -> Result<(),OxError> {
+pub fn compile_shader(shader: &Shader) -> Result<(),OxError> {
     log::trace!("compiling shader: {}", shader.0);
     safe_bindings::CompileShader(shader.0);
     last_error_as_result()?;
@@ -446,7 +658,7 @@ pub enum ShaderCompileStatus {
 }
 /// # Errors
 /// `GL_INVALID_VALUE`: shader has been deleted
-pub fn get_shader_compile_status(shader: Shader) -> Result<ShaderCompileStatus, OxError> {
+pub fn get_shader_compile_status(shader: &Shader) -> Result<ShaderCompileStatus, OxError> {
     log::trace!("getting shader {} compile status", shader.0);
     let mut data = 0;
     safe_bindings::GetShaderiv(shader.0, safe_bindings::ShaderParameter::CompileStatus, &mut data);
@@ -467,7 +679,7 @@ pub enum ShaderDeleteStatus {
 }
 /// # Errors
 /// `GL_INVALID_VALUE`: shader has been deleted
-pub fn get_shader_delete_status(shader: Shader) -> Result<ShaderDeleteStatus, OxError> {
+pub fn get_shader_delete_status(shader: &Shader) -> Result<ShaderDeleteStatus, OxError> {
     let mut data = 0;
     safe_bindings::GetShaderiv(shader.0, safe_bindings::ShaderParameter::DeleteStatus, &mut data);
     log::trace!("got shader {} delete status = {data}", shader.0);
@@ -482,7 +694,7 @@ pub fn get_shader_delete_status(shader: Shader) -> Result<ShaderDeleteStatus, Ox
 }
 /// # Errors
 /// `GL_INVALID_OPERATION`: shader has been deleted
-pub fn get_shader_info_log_length(shader: Shader) -> Result<usize, OxError> {
+pub fn get_shader_info_log_length(shader: &Shader) -> Result<usize, OxError> {
     log::trace!("getting shader {} log length", shader.0);
     let mut data = 0;
     safe_bindings::GetShaderiv(shader.0, safe_bindings::ShaderParameter::InfoLogLength, &mut data);
@@ -493,7 +705,7 @@ pub fn get_shader_info_log_length(shader: Shader) -> Result<usize, OxError> {
 }
 /// # Errors
 /// `GL_INVALID_OPERATION`: shader has been deleted
-pub fn get_shader_info_log(shader: Shader) -> Result<String, OxError> {
+pub fn get_shader_info_log(shader: &Shader) -> Result<String, OxError> {
     log::trace!("getting shader {} info log", shader.0);
     let mut buffer = vec![0; get_shader_info_log_length(shader)?];
     // shader valid as it successfully got the length -> no need for error checking
@@ -512,7 +724,8 @@ pub fn get_shader_info_log(shader: Shader) -> Result<String, OxError> {
 //
 // Shader Programs
 //
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// The name of a shader program, which is unique: not Clone, so that it is only deleted by its owner
+#[derive(Debug, PartialEq, Eq)]
 pub struct ShaderProgram(u32);
 /// # Errors
 ///
@@ -529,7 +742,7 @@ pub fn create_program() -> Result<ShaderProgram, OxError> {
 }
 /// # Errors
 /// `GL_INVALID_VALUE`: program was deleted
-pub fn delete_program(program: ShaderProgram) -> Result<(),OxError> {
+pub fn delete_program(program: &ShaderProgram) -> Result<(),OxError> {
     log::trace!("deleting shader program: {}", program.0);
     safe_bindings::DeleteProgram(program.0);
     last_error_as_result()
@@ -538,7 +751,7 @@ pub fn delete_program(program: ShaderProgram) -> Result<(),OxError> {
 /// # Errors
 /// `GL_INVALID_VALUE`: program was deleted
 /// `GL_INVALID_OPERATION`: program is active, and transform feedback mode is active
-pub fn link_program(program: ShaderProgram) -> Result<(),OxError> {
+pub fn link_program(program: &ShaderProgram) -> Result<(),OxError> {
     log::trace!("linking shader program: {}", program.0);
     safe_bindings::LinkProgram(program.0);
     last_error_as_result()?;
@@ -557,7 +770,7 @@ pub enum LinkStatus {
 }
 /// # Errors
 /// `GL_INVALID_OPERATION`: program deleted
-pub fn get_program_link_status(program: ShaderProgram) -> Result<LinkStatus, OxError> {
+pub fn get_program_link_status(program: &ShaderProgram) -> Result<LinkStatus, OxError> {
     log::trace!("getting shader program {} link status", program.0);
     let mut link_success = 0;
     safe_bindings::GetProgramiv(program.0, safe_bindings::ProgramParameter::LinkStatus, &mut link_success);
@@ -572,7 +785,7 @@ pub fn get_program_link_status(program: ShaderProgram) -> Result<LinkStatus, OxE
 }
 /// # Errors
 /// `GL_INVALID_OPERATION`: program was deleted
-pub fn get_program_info_log_length(program: ShaderProgram) -> Result<usize, OxError> {
+pub fn get_program_info_log_length(program: &ShaderProgram) -> Result<usize, OxError> {
     log::trace!("getting shader program {} info log", program.0);
     let mut data = 0;
     safe_bindings::GetProgramiv(program.0, safe_bindings::ProgramParameter::InfoLogLength, &mut data);
@@ -584,7 +797,7 @@ pub fn get_program_info_log_length(program: ShaderProgram) -> Result<usize, OxEr
 
 /// # Errors
 /// `GL_INVALID_OPERATION`: program was deleted
-pub fn get_program_info_log(program: ShaderProgram) -> Result<String, OxError> {
+pub fn get_program_info_log(program: &ShaderProgram) -> Result<String, OxError> {
     log::trace!("getting shader program {} info log", program.0);
     let mut buffer = vec![0; get_program_info_log_length(program)?];
     safe_bindings::GetProgramInfoLog(program.0, buffer.as_mut_slice(), None);
@@ -602,7 +815,7 @@ pub fn get_program_info_log(program: ShaderProgram) -> Result<String, OxError> {
 /// # Errors
 /// `GL_INVALID_VALUE`: shader, program deleted
 /// `GL_INVALID_OPERATON`: shader is already attached to program
-pub fn attach_shader(program: ShaderProgram, shader: Shader) -> Result<(), OxError> {
+pub fn attach_shader(program: &ShaderProgram, shader: &Shader) -> Result<(), OxError> {
     log::trace!("attaching shader {} to shader program {}", shader.0, program.0);
     safe_bindings::AttachShader(program.0, shader.0);
     last_error_as_result()
@@ -610,15 +823,35 @@ pub fn attach_shader(program: ShaderProgram, shader: Shader) -> Result<(), OxErr
 /// # Errors
 /// `GL_INVALID_VALUE`: shader, program deleted
 /// `GL_INVALID_OPERATON`: shader is not attached to program
-pub fn detach_shader(program: ShaderProgram, shader: Shader) -> Result<(), OxError> {
+pub fn detach_shader(program: &ShaderProgram, shader: &Shader) -> Result<(), OxError> {
     log::trace!("attaching shader program {} from shader program {}", shader.0, program.0);
     safe_bindings::DetachShader(program.0, shader.0);
     last_error_as_result()
 }
+/// Returns "None" if `name` is not an active uniform block in `program`
+/// # Errors
+/// `GL_INVALID_OPERATION`: program was deleted
+pub fn get_uniform_block_index(program: &ShaderProgram, name: &std::ffi::CStr) -> Result<Option<u32>,OxError> {
+    log::trace!("getting index of uniform block {name:?} in shader program {}", program.0);
+    let index = safe_bindings::GetUniformBlockIndex(program.0, name);
+    last_error_as_result()?;
+    match index {
+        safe_bindings::glInvalidIndex => Ok(None),
+        index => Ok(Some(index))
+    }
+}
+/// # Errors
+/// `GL_INVALID_VALUE`: `block_index` is not an active uniform block of program,
+///                     binding >= `GL_MAX_UNIFORM_BUFFER_BINDINGS`, or program deleted
+pub fn uniform_block_binding(program: &ShaderProgram, block_index: u32, binding: u32) -> Result<(),OxError> {
+    log::trace!("binding uniform block {block_index} of shader program {} to binding point {binding}", program.0);
+    safe_bindings::UniformBlockBinding(program.0, block_index, binding);
+    last_error_as_result()
+}
 /// # Errors
 /// `GL_INVALID_VALUE`: program deleted
 /// `GL_INVALID_OPERATON`: transform feedback mode is active
-pub fn use_program(program: ShaderProgram) -> Result<(),OxError> {
+pub fn use_program(program: &ShaderProgram) -> Result<(),OxError> {
     log::trace!("using shader program {}", program.0);
     safe_bindings::UseProgram(program.0);
     last_error_as_result()
@@ -649,3 +882,235 @@ pub fn draw_arrays(mode: DrawMode, first: usize, count: usize) -> Result<(),OxEr
     safe_bindings::DrawArrays(mode, first, count);
     last_error_as_result()
 }
+/// `base_vertex` is added to each element, before it indexes the vertices
+///
+/// # Errors
+/// `GL_INVALID_OPERATON`: a geometry shader is active and mode is incompatible with the input primitive type
+///                         of the geometry shader in the currently installed program object.
+/// `GL_INVALID_OPERATON`: non-zero buffer object name is bound to an enabled array or the element array
+///                         and the buffer object's data store is currently mapped
+pub fn draw_elements_base_vertex(mode: DrawMode, count: usize, index_type: IndexType, offset: usize,
+    base_vertex: i32) -> Result<(),OxError> {
+    log::trace!("drawing {count} vertices from elements of type {index_type:?} in mode {mode:?}, starting from {offset}, offset by {base_vertex} vertices");
+    safe_bindings::DrawElementsBaseVertex(mode, count, index_type, offset, base_vertex);
+    last_error_as_result()
+}
+/// `base_instance` is added to the instance, before it indexes instanced attributes
+///
+/// # Errors
+/// `GL_INVALID_OPERATON`: a geometry shader is active and mode is incompatible with the input primitive type
+///                         of the geometry shader in the currently installed program object.
+/// `GL_INVALID_OPERATON`: non-zero buffer object name is bound to an enabled array
+///                         and the buffer object's data store is currently mapped
+pub fn draw_arrays_instanced_base_instance(mode: DrawMode, first: usize, count: usize, instance_count: usize,
+    base_instance: u32) -> Result<(),OxError> {
+    log::trace!("drawing {instance_count} instances of {count} vertices from arrays in mode {mode:?}, starting from {first}, and from instance {base_instance}");
+    safe_bindings::DrawArraysInstancedBaseInstance(mode, first, count, instance_count, base_instance);
+    last_error_as_result()
+}
+/// `base_vertex` is added to each element, before it indexes the vertices, and `base_instance`
+/// to the instance, before it indexes instanced attributes
+///
+/// # Errors
+/// `GL_INVALID_OPERATON`: a geometry shader is active and mode is incompatible with the input primitive type
+///                         of the geometry shader in the currently installed program object.
+/// `GL_INVALID_OPERATON`: non-zero buffer object name is bound to an enabled array or the element array
+///                         and the buffer object's data store is currently mapped
+pub fn draw_elements_instanced_base_vertex_base_instance(mode: DrawMode, count: usize, index_type: IndexType,
+    offset: usize, instance_count: usize, base_vertex: i32, base_instance: u32) -> Result<(),OxError> {
+    log::trace!("drawing {instance_count} instances of {count} vertices from elements of type {index_type:?} in mode {mode:?}, starting from {offset}, offset by {base_vertex} vertices, and from instance {base_instance}");
+    safe_bindings::DrawElementsInstancedBaseVertexBaseInstance(mode, count, index_type, offset, instance_count,
+        base_vertex, base_instance);
+    last_error_as_result()
+}
+/// # Errors
+/// `GL_INVALID_OPERATON`: a geometry shader is active and mode is incompatible with the input primitive type
+///                         of the geometry shader in the currently installed program object.
+/// `GL_INVALID_OPERATON`: non-zero buffer object name is bound to an enabled array or the indirect buffer
+///                         and the buffer object's data store is currently mapped
+/// `GL_INVALID_OPERATON`: no indirect buffer is bound, or the command would be read beyond its end
+pub fn draw_arrays_indirect(mode: DrawMode, offset: usize) -> Result<(),OxError> {
+    log::trace!("drawing arrays in mode {mode:?} from the indirect command at {offset}");
+    safe_bindings::DrawArraysIndirect(mode, offset);
+    last_error_as_result()
+}
+/// # Errors
+/// `GL_INVALID_OPERATON`: a geometry shader is active and mode is incompatible with the input primitive type
+///                         of the geometry shader in the currently installed program object.
+/// `GL_INVALID_OPERATON`: non-zero buffer object name is bound to an enabled array, the element array or the
+///                         indirect buffer and the buffer object's data store is currently mapped
+/// `GL_INVALID_OPERATON`: no indirect or element buffer is bound, or the command would be read beyond the end
+///                         of the indirect buffer
+pub fn draw_elements_indirect(mode: DrawMode, index_type: IndexType, offset: usize) -> Result<(),OxError> {
+    log::trace!("drawing elements of type {index_type:?} in mode {mode:?} from the indirect command at {offset}");
+    safe_bindings::DrawElementsIndirect(mode, index_type, offset);
+    last_error_as_result()
+}
+/// # Errors
+/// As for [`draw_arrays_indirect`]
+pub fn multi_draw_arrays_indirect(mode: DrawMode, offset: usize, draw_count: usize, stride: usize)
+    -> Result<(),OxError> {
+    log::trace!("drawing arrays in mode {mode:?} from {draw_count} indirect commands, starting from {offset}");
+    safe_bindings::MultiDrawArraysIndirect(mode, offset, draw_count, stride);
+    last_error_as_result()
+}
+/// # Errors
+/// As for [`draw_elements_indirect`]
+pub fn multi_draw_elements_indirect(mode: DrawMode, index_type: IndexType, offset: usize, draw_count: usize,
+    stride: usize) -> Result<(),OxError> {
+    log::trace!("drawing elements of type {index_type:?} in mode {mode:?} from {draw_count} indirect commands, starting from {offset}");
+    safe_bindings::MultiDrawElementsIndirect(mode, index_type, offset, draw_count, stride);
+    last_error_as_result()
+}
+
+//
+// Pixels
+//
+pub use safe_bindings::{PixelFormat, PixelDataType};
+/// Read a block of pixels from the framebuffer into the buffer bound to `GL_PIXEL_PACK_BUFFER`,
+/// at `offset` bytes
+///
+/// # Errors
+/// `GL_INVALID_OPERATON`: format and data type are incompatible with each other or the framebuffer,
+///                        the pack buffer is mapped, the data would exceed the pack buffer,
+///                        offset is misaligned for the data type
+/// `GL_INVALID_FRAMEBUFFER_OPERATION`: the read framebuffer is not complete
+///
+/// # Safety
+/// A buffer must be bound to `GL_PIXEL_PACK_BUFFER`, otherwise `offset` is a pointer into client
+/// memory that OpenGL writes to
+pub unsafe fn read_pixels(x: i32, y: i32, width: usize, height: usize, format: PixelFormat,
+    data_type: PixelDataType, offset: usize) -> Result<(),OxError> {
+    log::trace!("reading {width}x{height} pixels at ({x}, {y}) as {format:?} {data_type:?}, to offset {offset}");
+    // SAFETY: the caller guarantees a pack buffer is bound
+    unsafe {
+        safe_bindings::ReadPixels(x, y, width, height, format, data_type, offset);
+    }
+    last_error_as_result()
+}
+
+//
+// Textures
+//
+/// The name of a texture, which is unique: not Clone, so that it is only deleted by its owner
+#[derive(Debug, PartialEq, Eq)]
+pub struct Texture(u32);
+#[allow(clippy::must_use_candidate)]    // basically constructor, if it's called, it will be used
+pub fn gen_texture() -> Texture {
+    let mut id = 0;
+    safe_bindings::GenTexture(&mut id);
+    log::trace!("generated texture: {id}");
+    Texture(id)
+}
+pub fn delete_texture(texture: &Texture) {
+    safe_bindings::DeleteTexture(texture.0);
+    log::trace!("deleted texture: {}", texture.0);
+}
+pub use safe_bindings::{TextureTarget, TextureFormat};
+/// # Errors
+/// `GL_INVALID_VALUE`: `texture` was deleted
+/// `GL_INVALID_OPERATON`: `texture` was created with a different target
+pub fn bind_texture(target: TextureTarget, texture: Option<&Texture>) -> Result<(),OxError> {
+    log::trace!("binding texture {texture:?} to {target:?}");
+    safe_bindings::BindTexture(target, texture.map_or(0, |t| t.0));
+    last_error_as_result()
+}
+/// Select the texture unit that textures are bound to
+///
+/// # Notes
+/// `unit` must be < `GL_MAX_COMBINED_TEXTURE_IMAGE_UNITS`, as OpenGL would not report it
+pub fn active_texture(unit: u32) {
+    log::trace!("activating texture unit {unit}");
+    safe_bindings::ActiveTexture(unit);
+}
+/// Allocate immutable storage for `levels` mipmap levels of the bound texture
+///
+/// # Errors
+/// `GL_INVALID_OPERATON`: no texture is bound, its storage is already immutable,
+///                        or there are too many levels for its size
+/// `GL_INVALID_VALUE`: width or height are 0, or > `GL_MAX_TEXTURE_SIZE`
+pub fn tex_storage_2d(target: TextureTarget, levels: usize, internal_format: TextureFormat,
+    width: usize, height: usize) -> Result<(),OxError> {
+    log::trace!("allocating {levels} levels of {width}x{height} {internal_format:?} for {target:?}");
+    safe_bindings::TexStorage2D(target, levels, internal_format, width, height);
+    last_error_as_result()
+}
+/// Upload a block of pixels from the buffer bound to `GL_PIXEL_UNPACK_BUFFER`, at `offset` bytes,
+/// into the bound texture
+///
+/// # Errors
+/// `GL_INVALID_OPERATON`: format and data type are incompatible with each other or the texture,
+///                        the unpack buffer is mapped, the data would exceed the unpack buffer,
+///                        offset is misaligned for the data type
+/// `GL_INVALID_VALUE`: the block lies outside the level, or the level is invalid
+///
+/// # Safety
+/// A buffer must be bound to `GL_PIXEL_UNPACK_BUFFER`, otherwise `offset` is a pointer into client
+/// memory that OpenGL reads from
+#[allow(clippy::too_many_arguments)]
+pub unsafe fn tex_sub_image_2d(target: TextureTarget, level: usize, x: i32, y: i32, width: usize, height: usize,
+    format: PixelFormat, data_type: PixelDataType, offset: usize) -> Result<(),OxError> {
+    log::trace!("uploading {width}x{height} pixels at ({x}, {y}) of level {level} of {target:?} as {format:?} {data_type:?}, from offset {offset}");
+    // SAFETY: the caller guarantees an unpack buffer is bound
+    unsafe {
+        safe_bindings::TexSubImage2D(target, level, x, y, width, height, format, data_type, offset);
+    }
+    last_error_as_result()
+}
+/// Read a whole level of the bound texture into the buffer bound to `GL_PIXEL_PACK_BUFFER`,
+/// at `offset` bytes
+///
+/// # Errors
+/// `GL_INVALID_OPERATON`: format and data type are incompatible with each other or the texture,
+///                        the pack buffer is mapped, the data would exceed the pack buffer,
+///                        offset is misaligned for the data type
+/// `GL_INVALID_VALUE`: the level is invalid
+///
+/// # Safety
+/// A buffer must be bound to `GL_PIXEL_PACK_BUFFER`, otherwise `offset` is a pointer into client
+/// memory that OpenGL writes to
+pub unsafe fn get_tex_image(target: TextureTarget, level: usize, format: PixelFormat, data_type: PixelDataType,
+    offset: usize) -> Result<(),OxError> {
+    log::trace!("reading level {level} of {target:?} as {format:?} {data_type:?}, to offset {offset}");
+    // SAFETY: the caller guarantees a pack buffer is bound
+    unsafe {
+        safe_bindings::GetTexImage(target, level, format, data_type, offset);
+    }
+    last_error_as_result()
+}
+
+//
+// Synchronisation
+//
+/// A fence, which is signalled once the commands issued before it complete
+#[derive(Debug, PartialEq, Eq)]
+pub struct Fence(safe_bindings::GLsync);
+#[allow(clippy::must_use_candidate)]    // basically constructor, if it's called, it will be used
+pub fn fence_sync() -> Fence {
+    let sync = safe_bindings::FenceSync();
+    log::trace!("created fence: {sync:?}");
+    Fence(sync)
+}
+pub use safe_bindings::WaitStatus;
+/// Block until `sync` is signalled, or `timeout` nanoseconds pass, flushing the commands
+/// before it if `flush` is set
+///
+/// # Errors
+/// `GL_INVALID_VALUE`: sync has been deleted
+pub fn client_wait_sync(sync: &Fence, flush: bool, timeout: u64) -> Result<WaitStatus,OxError> {
+    log::trace!("waiting on fence {:?} for {timeout}ns", sync.0);
+    let status = safe_bindings::ClientWaitSync(sync.0, flush, timeout);
+    last_error_as_result()?;
+    Ok(status)
+}
+#[allow(clippy::needless_pass_by_value)]     // the fence must not be used once deleted
+pub fn delete_sync(sync: Fence) {
+    safe_bindings::DeleteSync(sync.0);
+    log::trace!("deleted fence: {:?}", sync.0);
+}
+pub use safe_bindings::MemoryBarrierFlags;
+/// Make shader writes visible to the accesses of the given kinds issued after this
+pub fn memory_barrier(barriers: MemoryBarrierFlags) {
+    log::trace!("memory barrier for {barriers:?}");
+    safe_bindings::MemoryBarrier(barriers);
+}
@@ -1,5 +1,9 @@
 pub(crate) use crate::errors::ToOwlError;
 
-pub use crate::traits::ToByteVec;
-pub use trait_derives::ToByteVec;
+pub use crate::traits::{ToByteVec, FromByteVec, Pod, IndexElement};
+pub use trait_derives::{ToByteVec, FromByteVec};
+pub use crate::traits::{Std140, Std430, GlslArray};
+pub use trait_derives::{Std140, Std430};
+pub use crate::traits::{Vertex, VertexField, IntegralVertexField, DoubleVertexField};
+pub use trait_derives::Vertex;
 pub use crate::traits::Bytes;
@@ -132,6 +132,29 @@ pub enum BufferType {
     TransformFeedback,
     Uniform
 }
+/// The subset of [`BufferType`]s that have indexed binding points
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum IndexedBufferType {
+    AtomicCounter,
+    ShaderStorage,
+    TransformFeedback,
+    Uniform
+}
+/// # GL Invariants
+/// target: accepted indexed buffer target (GLenum)
+///
+/// # User Invariants
+/// index: < the number of binding points for target
+/// buffer: is a valid buffer returned by `glGenBuffers` or 0
+///
+/// # Errors
+/// `GL_INVALID_VALUE`: index >= the number of binding points for target,
+///                     buffer was not returned by `glGenBuffers`, 0, or was deleted
+#[inline]
+pub fn BindBufferBase(target: IndexedBufferType, index: u32, buffer: u32) {
+    // SAFETY: FFI
+    unsafe { gl::BindBufferBase(target.into(), index, buffer) }
+}
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum BufferUsage {
     StreamDraw,
@@ -203,6 +226,324 @@ pub fn BufferSubData<T>(target: BufferType, data: &[T], offset: usize) {
              data.as_ptr().cast());
     }
 }
+/// Allocate `size` bytes, without initialising them
+///
+/// # GL Invariants
+/// target: accepted buffer target (GLenum),
+/// size >= 0,
+/// usage: accepted buffer usage (GLenum)
+///
+/// # User Invariants
+/// `GL_BUFFER_IMMUTABLE_STORAGE` flag of target must be `GL_FALSE`,
+/// A buffer must be bound
+///
+/// # Errors
+/// `GL_INVALID_OPERATON`: `GL_BUFFER_IMMUTABLE_STORAGE` flag of target set to `GL_TRUE`, no buffer bound
+/// `GL_OUT_OF_MEMORY`
+///
+/// # Panics
+/// This will panic if the size is > `isize::MAX`
+/// that's the maximum amount of data a buffer can store anyway.
+#[inline]
+pub fn BufferDataUninitialised(target: BufferType, size: usize, usage: BufferUsage) {
+    // SAFETY: a null pointer means that no data is copied
+    unsafe {
+        gl::BufferData(target.into(),
+            isize::try_from(size).expect("size > isize::MAX"),
+            std::ptr::null(), usage.into());
+    }
+}
+/// # GL Invariants
+/// target: accepted buffer target (GLenum),
+/// size, offest >= 0,
+///
+/// # User Invariants
+/// A buffer must be bound, and not mapped (unless persistently)
+/// offset + size <= buffer size
+///
+/// # Notes
+/// `offset`: measured in bytes
+///
+/// # Errors
+/// `GL_INVALID_OPERATON`: zero is bound to target, target is being mapped
+/// `GL_INVALID_VALUE`: offset + size > buffer size
+///
+/// # Panics
+/// This will panic if the offset is > `isize::MAX`
+/// that's the maximum amount of data a buffer can store anyway.
+#[inline]
+pub fn GetBufferSubData(target: BufferType, offset: usize, data: &mut [u8]) {
+    // SAFETY: the pointer to the data slice is non-null, and valid for writes
+    //         over the length of the slice.
+    unsafe {
+        #[allow(clippy::cast_possible_wrap)]
+        gl::GetBufferSubData(target.into(),
+            isize::try_from(offset).expect("offset > isize::MAX"),
+            data.len() as isize,
+            data.as_mut_ptr().cast());
+    }
+}
+/// # GL Invariants
+/// `read_target`, `write_target`: accepted buffer targets (GLenum),
+/// offsets, size >= 0
+///
+/// # User Invariants
+/// A buffer must be bound to each target, and neither may be mapped (unless persistently)
+/// `read_offset` + size <= read buffer size, `write_offset` + size <= write buffer size
+/// if the same buffer is bound to both, the ranges must not overlap
+///
+/// # Notes
+/// offsets and size are measured in bytes
+///
+/// # Errors
+/// `GL_INVALID_OPERATON`: zero is bound to either target, either buffer is being mapped
+/// `GL_INVALID_VALUE`: either range exceeds its buffer, the ranges overlap within the same buffer
+///
+/// # Panics
+/// This will panic if the offsets or size > `isize::MAX`,
+/// that's the maximum amount of data a buffer can store anyway.
+#[inline]
+pub fn CopyBufferSubData(read_target: BufferType, write_target: BufferType, read_offset: usize,
+    write_offset: usize, size: usize) {
+    // SAFETY: FFI, no client memory is accessed
+    unsafe {
+        gl::CopyBufferSubData(read_target.into(), write_target.into(),
+            isize::try_from(read_offset).expect("read offset > isize::MAX"),
+            isize::try_from(write_offset).expect("write offset > isize::MAX"),
+            isize::try_from(size).expect("size > isize::MAX"));
+    }
+}
+
+bitflags! (
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    pub struct BufferStorageFlags: u32 {
+        const DynamicStorage = 1;
+        const MapRead = 1 << 1;
+        const MapWrite = 1 << 2;
+        const MapPersistent = 1 << 3;
+        const MapCoherent = 1 << 4;
+        const ClientStorage = 1 << 5;
+    }
+);
+impl From<BufferStorageFlags> for gl::types::GLbitfield {
+    fn from(val: BufferStorageFlags) -> Self {
+        let mut bitfield = 0;
+        if val.contains(BufferStorageFlags::DynamicStorage) {
+            bitfield |= gl::DYNAMIC_STORAGE_BIT;
+        }
+        if val.contains(BufferStorageFlags::MapRead) {
+            bitfield |= gl::MAP_READ_BIT;
+        }
+        if val.contains(BufferStorageFlags::MapWrite) {
+            bitfield |= gl::MAP_WRITE_BIT;
+        }
+        if val.contains(BufferStorageFlags::MapPersistent) {
+            bitfield |= gl::MAP_PERSISTENT_BIT;
+        }
+        if val.contains(BufferStorageFlags::MapCoherent) {
+            bitfield |= gl::MAP_COHERENT_BIT;
+        }
+        if val.contains(BufferStorageFlags::ClientStorage) {
+            bitfield |= gl::CLIENT_STORAGE_BIT;
+        }
+        bitfield
+    }
+}
+/// # GL Invariants
+/// target: accepted buffer target (GLenum),
+/// size > 0
+///
+/// # User Invariants
+/// `GL_BUFFER_IMMUTABLE_STORAGE` flag of target must be `GL_FALSE`,
+/// A buffer must be bound
+/// flags: `MapPersistent` only alongside `MapRead` or `MapWrite`,
+///     `MapCoherent` only alongside `MapPersistent`
+///
+/// # Errors
+/// `GL_INVALID_OPERATON`: `GL_BUFFER_IMMUTABLE_STORAGE` flag of target set to `GL_TRUE`, no buffer bound
+/// `GL_INVALID_VALUE`: size == 0, any of the flags invariants are violated
+/// `GL_OUT_OF_MEMORY`
+///
+/// # Notes
+/// Any data after `isize::MAX` bytes will be truncated - OpenGL limitation
+#[inline]
+pub fn BufferStorage<T>(target: BufferType, data: &[T], flags: BufferStorageFlags) {
+    // SAFETY: the pointer to the data slice is non-null, aligned,
+    //         and initialised over the length of the slice.
+    unsafe {
+        #[allow(clippy::cast_possible_wrap)]
+        gl::BufferStorage(target.into(),
+            std::mem::size_of_val(data) as isize,
+            data.as_ptr().cast(), flags.into());
+    }
+}
+/// # GL Invariants
+/// target: accepted buffer target (GLenum),
+/// size > 0
+///
+/// # User Invariants
+/// As for [`BufferStorage`]
+///
+/// # Errors
+/// As for [`BufferStorage`]
+///
+/// # Panics
+/// This will panic if the size is > `isize::MAX`
+#[inline]
+pub fn BufferStorageUninitialised(target: BufferType, size: usize, flags: BufferStorageFlags) {
+    // SAFETY: a null pointer means that no data is copied
+    unsafe {
+        gl::BufferStorage(target.into(),
+            isize::try_from(size).expect("size > isize::MAX"),
+            std::ptr::null(), flags.into());
+    }
+}
+
+bitflags! (
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    pub struct MapAccessFlags: u32 {
+        const Read = 1;
+        const Write = 1 << 1;
+        const InvalidateRange = 1 << 2;
+        const InvalidateBuffer = 1 << 3;
+        const FlushExplicit = 1 << 4;
+        const Unsynchronized = 1 << 5;
+        const Persistent = 1 << 6;
+        const Coherent = 1 << 7;
+    }
+);
+impl From<MapAccessFlags> for gl::types::GLbitfield {
+    fn from(val: MapAccessFlags) -> Self {
+        let mut bitfield = 0;
+        if val.contains(MapAccessFlags::Read) {
+            bitfield |= gl::MAP_READ_BIT;
+        }
+        if val.contains(MapAccessFlags::Write) {
+            bitfield |= gl::MAP_WRITE_BIT;
+        }
+        if val.contains(MapAccessFlags::InvalidateRange) {
+            bitfield |= gl::MAP_INVALIDATE_RANGE_BIT;
+        }
+        if val.contains(MapAccessFlags::InvalidateBuffer) {
+            bitfield |= gl::MAP_INVALIDATE_BUFFER_BIT;
+        }
+        if val.contains(MapAccessFlags::FlushExplicit) {
+            bitfield |= gl::MAP_FLUSH_EXPLICIT_BIT;
+        }
+        if val.contains(MapAccessFlags::Unsynchronized) {
+            bitfield |= gl::MAP_UNSYNCHRONIZED_BIT;
+        }
+        if val.contains(MapAccessFlags::Persistent) {
+            bitfield |= gl::MAP_PERSISTENT_BIT;
+        }
+        if val.contains(MapAccessFlags::Coherent) {
+            bitfield |= gl::MAP_COHERENT_BIT;
+        }
+        bitfield
+    }
+}
+/// # GL Invariants
+/// target: accepted buffer target (GLenum),
+/// offset, length >= 0
+///
+/// # User Invariants
+/// A buffer must be bound, and not already mapped
+/// length > 0, offset + length <= buffer size
+/// access: contains `Read` or `Write`, but not `Read` along with `InvalidateRange`,
+///     `InvalidateBuffer` or `Unsynchronized`; `FlushExplicit` only alongside `Write`;
+///     `Read`, `Write`, `Persistent` and `Coherent` only if the buffer's storage allows them
+///
+/// # Errors
+/// `GL_INVALID_VALUE`: length == 0, offset + length > buffer size
+/// `GL_INVALID_OPERATON`: zero is bound to target, buffer is already mapped,
+///                        any of the access invariants are violated
+/// `GL_OUT_OF_MEMORY`
+///
+/// # Notes
+/// returns a null pointer if the process fails
+///
+/// # Panics
+/// This will panic if the offset or length > `isize::MAX`,
+/// that's the maximum amount of data a buffer can store anyway.
+#[inline]
+pub fn MapBufferRange(target: BufferType, offset: usize, length: usize, access: MapAccessFlags) -> *mut std::ffi::c_void {
+    // SAFETY: FFI, the returned pointer is not dereferenced here
+    unsafe {
+        gl::MapBufferRange(target.into(),
+            isize::try_from(offset).expect("offset > isize::MAX"),
+            isize::try_from(length).expect("length > isize::MAX"),
+            access.into())
+    }
+}
+/// # GL Invariants
+/// target: accepted buffer target (GLenum)
+///
+/// # User Invariants
+/// A buffer must be bound, and mapped
+///
+/// # Errors
+/// `GL_INVALID_OPERATON`: zero is bound to target, buffer is not mapped
+///
+/// # Notes
+/// returns false if the buffer's data store was corrupted while mapped, and must be reinitialised
+#[inline]
+pub fn UnmapBuffer(target: BufferType) -> bool {
+    // SAFETY: FFI
+    unsafe {
+        gl::UnmapBuffer(target.into()) == gl::TRUE
+    }
+}
+
+/// # GL Invariants
+/// target: accepted buffer target (GLenum),
+/// offset, length >= 0
+///
+/// # User Invariants
+/// A buffer must be bound, and mapped with `FlushExplicit`
+/// offset + length <= mapped length
+///
+/// # Errors
+/// `GL_INVALID_VALUE`: offset + length > mapped length
+/// `GL_INVALID_OPERATON`: zero is bound to target, buffer is not mapped,
+///                        or mapped without `FlushExplicit`
+///
+/// # Notes
+/// offset is relative to the start of the mapped range
+///
+/// # Panics
+/// This will panic if the offset or length > `isize::MAX`,
+/// that's the maximum amount of data a buffer can store anyway.
+#[inline]
+pub fn FlushMappedBufferRange(target: BufferType, offset: usize, length: usize) {
+    // SAFETY: FFI
+    unsafe {
+        gl::FlushMappedBufferRange(target.into(),
+            isize::try_from(offset).expect("offset > isize::MAX"),
+            isize::try_from(length).expect("length > isize::MAX"));
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BufferParameter {
+    Size,
+    Mapped,
+}
+/// # GL Invariants
+/// target: accepted buffer target (GLenum)
+/// parameter: accepted value (GLenum)
+///
+/// # User Invariants
+/// A buffer must be bound
+///
+/// # Errors
+/// `GL_INVALID_OPERATON`: zero is bound to target
+#[inline]
+pub fn GetBufferParameteriv(target: BufferType, parameter: BufferParameter, data: &mut i32) {
+    // SAFETY: always returns a single value ==> ptr never out of bounds
+    unsafe {
+        gl::GetBufferParameteriv(target.into(), parameter.into(), data);
+    }
+}
 
 /// # GL Invariants
 /// length of `vertex_arrays` >= 0
@@ -272,6 +613,24 @@ pub fn EnableVertexAttribArray(attribute_index: u8) {
     }
 }
 
+/// # User Invariants
+/// vertex array object must be bound
+/// `attribute_index`: < `GL_MAX_VERTEX_ATTRIBS`
+///
+/// # Errors
+/// `GL_INVALID_OPERATON`: no vertex array object is bound
+/// `GL_INVALID_VALUE`: `attribute_index` >= `GL_MAX_VERTEX_ATTRIBS`
+///
+/// # Notes
+/// A divisor of 0 advances the attribute per vertex, otherwise once per `divisor` instances
+#[inline]
+pub fn VertexAttribDivisor(attribute_index: u8, divisor: u32) {
+    // SAFETY: FFI
+    unsafe {
+        gl::VertexAttribDivisor(u32::from(attribute_index), divisor);
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum AttribSize {
     One = 1,
@@ -345,6 +704,32 @@ pub fn VertexAttribIPointer(index: u8, size: IntegralAttribSize, data_type: Inte
     }
 }
 
+/// # GL Invariants
+/// size: 1,2,3,4
+/// stride: >= 0
+/// 
+/// # User Invariants
+/// index: < `GL_MAX_VERTEX_ATTRIBS`
+/// array buffer bound to 0; offset: != 0
+///
+/// # Errors
+/// `GL_INVALID_VALUE`: index >= `GL_MAX_VERTEX_ATTRIBS`
+/// `GL_INVALID_OPERATON`: any of the other user invariants are violated
+///
+/// # Panics
+/// This function will panic if the stride or offset > `i32::MAX`.
+/// ## Notes
+/// The type is always `GL_DOUBLE`, the only one accepted
+#[inline]
+pub fn VertexAttribLPointer(index: u8, size: IntegralAttribSize, stride: usize, offset: usize) {
+    // SAFETY: cast to void pointer, I'm told it's meant to be a 4-byte integer
+    unsafe {
+        gl::VertexAttribLPointer(u32::from(index), size.into(), gl::DOUBLE,
+            i32::try_from(stride).expect("stride > i32::MAX"),
+            i32::try_from(offset).expect("offset > i32::MAX") as *const std::ffi::c_void);
+    }
+}
+
 // TODO: all parameters... eish
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum Parameter {
@@ -352,6 +737,8 @@ pub enum Parameter {
     ArrayBufferBinding,
     ElementBufferBinding,
     MaxComputeShaderStorageBlocks,
+    MaxUniformBufferBindings,
+    MaxCombinedTextureImageUnits,
 }
 /// # GL Invariants
 /// parameter: an accepted value (GLenum)
@@ -635,35 +1022,73 @@ pub fn GetProgramInfoLog(program: u32, buffer: &mut [std::ffi::c_char], length:
 }
 
 /// # User Invariants
-/// program: valid program object or 0
+/// program: valid program object
 ///
-/// # Errors:
-/// `GL_INVALID_VALUE`: program is not a value generated by OpenGL or 0
+/// # Errors
+/// `GL_INVALID_VALUE`: program is not a value generated by OpenGL
 /// `GL_INVALID_OPERATON`: program is not a valid program object
-/// `GL_INVALID_OPERATON`: transform feedback mode is active
+///
+/// # Notes
+/// returns [`glInvalidIndex`] if `name` is not an active uniform block in program
 #[inline]
-pub fn UseProgram(program: u32) {
-    // SAFETY: FFI
+pub fn GetUniformBlockIndex(program: u32, name: &std::ffi::CStr) -> u32 {
+    // SAFETY: the pointer is to a valid nul-terminated string, which
+    //         will not be mutated elsewhere for the duration of this call.
     unsafe {
-        gl::UseProgram(program);
+        gl::GetUniformBlockIndex(program, name.as_ptr())
     }
 }
+/// Only for interop with [`GetUniformBlockIndex`]
+pub use gl::INVALID_INDEX as glInvalidIndex;
 
 /// # User Invariants
-/// program: value generated by OpenGL
+/// program: valid program object
+/// `block_index`: active uniform block index of program
+/// binding: < `GL_MAX_UNIFORM_BUFFER_BINDINGS`
 ///
 /// # Errors
-/// `GL_INVALID_VALUE`: program is not a value generated by OpenGL, or was deleted
+/// `GL_INVALID_VALUE`: `block_index` is not an active uniform block index of program,
+///                     binding >= `GL_MAX_UNIFORM_BUFFER_BINDINGS`, or
+///                     program is not a value generated by OpenGL
+/// `GL_INVALID_OPERATON`: program is not a valid program object
 #[inline]
-pub fn DeleteProgram(program: u32) {
+pub fn UniformBlockBinding(program: u32, block_index: u32, binding: u32) {
     // SAFETY: FFI
     unsafe {
-        gl::DeleteProgram(program);
+        gl::UniformBlockBinding(program, block_index, binding);
     }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
-pub enum DrawMode {
+/// # User Invariants
+/// program: valid program object or 0
+///
+/// # Errors:
+/// `GL_INVALID_VALUE`: program is not a value generated by OpenGL or 0
+/// `GL_INVALID_OPERATON`: program is not a valid program object
+/// `GL_INVALID_OPERATON`: transform feedback mode is active
+#[inline]
+pub fn UseProgram(program: u32) {
+    // SAFETY: FFI
+    unsafe {
+        gl::UseProgram(program);
+    }
+}
+
+/// # User Invariants
+/// program: value generated by OpenGL
+///
+/// # Errors
+/// `GL_INVALID_VALUE`: program is not a value generated by OpenGL, or was deleted
+#[inline]
+pub fn DeleteProgram(program: u32) {
+    // SAFETY: FFI
+    unsafe {
+        gl::DeleteProgram(program);
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DrawMode {
     Points,
     LineStrip,
     LineLoop,
@@ -730,6 +1155,573 @@ pub fn DrawArrays(mode: DrawMode, first: usize, count: usize) {
     }
 }
 
+/// # GL Invariants
+/// mode: accepted value (GLenum)
+/// count: >= 0
+///
+/// # User Invariants
+/// mode: incompatible with primitive type of the geometry shader
+/// _see second error below_
+///
+/// # Errors
+/// `GL_INVALID_OPERATON`: a geometry shader is active and mode is incompatible with the input primitive type
+///                         of the geometry shader in the currently installed program object.
+/// `GL_INVALID_OPERATON`: non-zero buffer object name is bound to an enabled array or the element array
+///                         and the buffer object's data store is currently mapped
+///
+/// # Notes
+/// `base_vertex` is added to each element, before it indexes the vertices
+///
+/// # Panics
+/// This function panics if count > `i32::MAX`
+#[inline]
+pub fn DrawElementsBaseVertex(mode: DrawMode, count: usize, index_type: IndexType, offset: usize, base_vertex: i32) {
+    // SAFETY: cast to void pointer, an offset into the bound element buffer
+    unsafe {
+        gl::DrawElementsBaseVertex(mode.into(), i32::try_from(count).expect("count > i32::MAX"),
+            index_type.into(), offset as *const std::ffi::c_void, base_vertex);
+    }
+}
+
+/// # GL Invariants
+/// mode: accepted value (GLenum)
+/// count, instance count: >= 0
+///
+/// # User Invariants
+/// mode: incompatible with primitive type of the geometry shader
+/// _see second error below_
+///
+/// # Errors
+/// `GL_INVALID_OPERATON`: a geometry shader is active and mode is incompatible with the input primitive type
+///                         of the geometry shader in the currently installed program object.
+/// `GL_INVALID_OPERATON`: non-zero buffer object name is bound to an enabled array
+///                         and the buffer object's data store is currently mapped
+///
+/// # Notes
+/// `base_instance` is added to the instance, before it indexes instanced attributes
+///
+/// # Panics
+/// This function panics if first, count, instance count > `i32::MAX`
+#[inline]
+pub fn DrawArraysInstancedBaseInstance(mode: DrawMode, first: usize, count: usize, instance_count: usize,
+    base_instance: u32) {
+    // SAFETY: FFI
+    unsafe {
+        gl::DrawArraysInstancedBaseInstance(mode.into(), i32::try_from(first).expect("first > i32::MAX"),
+            i32::try_from(count).expect("count > i32::MAX"),
+            i32::try_from(instance_count).expect("instance count > i32::MAX"), base_instance);
+    }
+}
+
+/// # GL Invariants
+/// mode: accepted value (GLenum)
+/// count, instance count: >= 0
+///
+/// # User Invariants
+/// mode: incompatible with primitive type of the geometry shader
+/// _see second error below_
+///
+/// # Errors
+/// `GL_INVALID_OPERATON`: a geometry shader is active and mode is incompatible with the input primitive type
+///                         of the geometry shader in the currently installed program object.
+/// `GL_INVALID_OPERATON`: non-zero buffer object name is bound to an enabled array or the element array
+///                         and the buffer object's data store is currently mapped
+///
+/// # Notes
+/// `base_vertex` is added to each element, before it indexes the vertices, and `base_instance`
+/// to the instance, before it indexes instanced attributes
+///
+/// # Panics
+/// This function panics if count, instance count > `i32::MAX`
+#[inline]
+pub fn DrawElementsInstancedBaseVertexBaseInstance(mode: DrawMode, count: usize, index_type: IndexType,
+    offset: usize, instance_count: usize, base_vertex: i32, base_instance: u32) {
+    // SAFETY: cast to void pointer, an offset into the bound element buffer
+    unsafe {
+        gl::DrawElementsInstancedBaseVertexBaseInstance(mode.into(), i32::try_from(count).expect("count > i32::MAX"),
+            index_type.into(), offset as *const std::ffi::c_void,
+            i32::try_from(instance_count).expect("instance count > i32::MAX"), base_vertex, base_instance);
+    }
+}
+
+/// # GL Invariants
+/// mode: accepted value (GLenum)
+/// offset: multiple of 4, within the buffer bound to `GL_DRAW_INDIRECT_BUFFER`
+///
+/// # User Invariants
+/// A non-zero buffer must be bound to `GL_DRAW_INDIRECT_BUFFER`, holding a `DrawArraysIndirectCommand` at `offset`
+/// mode: incompatible with primitive type of the geometry shader
+/// _see errors below_
+///
+/// # Errors
+/// `GL_INVALID_OPERATON`: a geometry shader is active and mode is incompatible with the input primitive type
+///                         of the geometry shader in the currently installed program object.
+/// `GL_INVALID_OPERATON`: non-zero buffer object name is bound to an enabled array or to the
+///                         `GL_DRAW_INDIRECT_BUFFER` binding and the buffer object's data store is currently mapped
+/// `GL_INVALID_OPERATON`: zero is bound to `GL_DRAW_INDIRECT_BUFFER`, or the command would be read beyond its end
+#[inline]
+pub fn DrawArraysIndirect(mode: DrawMode, offset: usize) {
+    // SAFETY: cast to void pointer, an offset into the bound indirect buffer
+    unsafe {
+        gl::DrawArraysIndirect(mode.into(), offset as *const std::ffi::c_void);
+    }
+}
+
+/// # GL Invariants
+/// mode: accepted value (GLenum)
+/// offset: multiple of 4, within the buffer bound to `GL_DRAW_INDIRECT_BUFFER`
+///
+/// # User Invariants
+/// A non-zero buffer must be bound to `GL_DRAW_INDIRECT_BUFFER`, holding a `DrawElementsIndirectCommand` at `offset`,
+/// and another to `GL_ELEMENT_ARRAY_BUFFER`
+/// mode: incompatible with primitive type of the geometry shader
+/// _see errors below_
+///
+/// # Errors
+/// `GL_INVALID_OPERATON`: a geometry shader is active and mode is incompatible with the input primitive type
+///                         of the geometry shader in the currently installed program object.
+/// `GL_INVALID_OPERATON`: non-zero buffer object name is bound to an enabled array, the element array or to the
+///                         `GL_DRAW_INDIRECT_BUFFER` binding and the buffer object's data store is currently mapped
+/// `GL_INVALID_OPERATON`: zero is bound to `GL_DRAW_INDIRECT_BUFFER` or `GL_ELEMENT_ARRAY_BUFFER`,
+///                         or the command would be read beyond the end of the indirect buffer
+#[inline]
+pub fn DrawElementsIndirect(mode: DrawMode, index_type: IndexType, offset: usize) {
+    // SAFETY: cast to void pointer, an offset into the bound indirect buffer
+    unsafe {
+        gl::DrawElementsIndirect(mode.into(), index_type.into(), offset as *const std::ffi::c_void);
+    }
+}
+
+/// # GL Invariants
+/// mode: accepted value (GLenum)
+/// offset: multiple of 4, within the buffer bound to `GL_DRAW_INDIRECT_BUFFER`
+/// stride: multiple of 4, or 0 for tightly packed commands
+///
+/// # User Invariants
+/// As for [`DrawArraysIndirect`], for each of the `draw_count` commands
+///
+/// # Errors
+/// `GL_INVALID_OPERATON`: as for [`DrawArraysIndirect`]
+///
+/// # Panics
+/// This function panics if `draw_count`,stride > `i32::MAX`
+#[inline]
+pub fn MultiDrawArraysIndirect(mode: DrawMode, offset: usize, draw_count: usize, stride: usize) {
+    // SAFETY: cast to void pointer, an offset into the bound indirect buffer
+    unsafe {
+        gl::MultiDrawArraysIndirect(mode.into(), offset as *const std::ffi::c_void,
+            i32::try_from(draw_count).expect("draw_count > i32::MAX"), i32::try_from(stride).expect("stride > i32::MAX"));
+    }
+}
+
+/// # GL Invariants
+/// mode: accepted value (GLenum)
+/// offset: multiple of 4, within the buffer bound to `GL_DRAW_INDIRECT_BUFFER`
+/// stride: multiple of 4, or 0 for tightly packed commands
+///
+/// # User Invariants
+/// As for [`DrawElementsIndirect`], for each of the `draw_count` commands
+///
+/// # Errors
+/// `GL_INVALID_OPERATON`: as for [`DrawElementsIndirect`]
+///
+/// # Panics
+/// This function panics if `draw_count`,stride > `i32::MAX`
+#[inline]
+pub fn MultiDrawElementsIndirect(mode: DrawMode, index_type: IndexType, offset: usize, draw_count: usize,
+    stride: usize) {
+    // SAFETY: cast to void pointer, an offset into the bound indirect buffer
+    unsafe {
+        gl::MultiDrawElementsIndirect(mode.into(), index_type.into(), offset as *const std::ffi::c_void,
+            i32::try_from(draw_count).expect("draw_count > i32::MAX"), i32::try_from(stride).expect("stride > i32::MAX"));
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PixelFormat {
+    Red,
+    Green,
+    Blue,
+    Rg,
+    Rgb,
+    Bgr,
+    Rgba,
+    Bgra,
+    RedInteger,
+    RgInteger,
+    RgbInteger,
+    RgbaInteger,
+    DepthComponent,
+    StencilIndex,
+    DepthStencil,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PixelDataType {
+    UnsignedByte,
+    Byte,
+    UnsignedShort,
+    Short,
+    UnsignedInt,
+    Int,
+    HalfFloat,
+    Float,
+    UnsignedInt24_8,
+    Float32UnsignedInt24_8Rev,
+}
+/// Read a block of pixels from the framebuffer into the buffer bound to `GL_PIXEL_PACK_BUFFER`
+///
+/// # GL Invariants
+/// width, height >= 0
+/// format, data type: accepted values (GLenum)
+///
+/// # Safety
+/// A buffer must be bound to `GL_PIXEL_PACK_BUFFER`, otherwise `offset` is a pointer into client
+/// memory that OpenGL writes to
+///
+/// # User Invariants
+/// The pack buffer must not be mapped
+/// The pixels written at `offset` must fit within the buffer, with `offset` a multiple of
+/// the data type's size
+/// format and data type must be compatible with each other, and with the framebuffer
+///
+/// # Notes
+/// `offset`: measured in bytes
+///
+/// # Errors
+/// `GL_INVALID_OPERATON`: format and data type are incompatible with each other or the framebuffer,
+///                        the pack buffer is mapped, the data would exceed the pack buffer,
+///                        offset is misaligned for the data type
+/// `GL_INVALID_FRAMEBUFFER_OPERATION`: the read framebuffer is not complete
+///
+/// # Panics
+/// This will panic if the width, height > `i32::MAX`.
+#[inline]
+pub unsafe fn ReadPixels(x: i32, y: i32, width: usize, height: usize, format: PixelFormat,
+    data_type: PixelDataType, offset: usize) {
+    // SAFETY: the caller guarantees a buffer is bound to GL_PIXEL_PACK_BUFFER,
+    //         so the pointer is an offset into it, which is never dereferenced
+    unsafe {
+        gl::ReadPixels(x, y, i32::try_from(width).expect("width > i32::MAX"),
+            i32::try_from(height).expect("height > i32::MAX"),
+            format.into(), data_type.into(), offset as *mut std::ffi::c_void);
+    }
+}
+
+/// # GL Invariants
+/// length of `textures` >= 0
+///
+/// # Panics
+/// This will panic if you request more than `i32::MAX` textures at once.
+/// You don't need that many
+#[inline]
+pub fn GenTextures(textures: &mut [u32]) {
+    // SAFETY: the pointer to the slice is aligned, and
+    //         will not be mutated elsewhere for the duration of this call.
+    //         the write is constrained by the length of the slice, and so
+    //         will not write outside of it
+    unsafe {
+        gl::GenTextures(i32::try_from(textures.len()).expect("number of textures > i32::MAX"),
+            textures.as_mut_ptr());
+    }
+}
+/// Ease of use for [`GenTextures`]
+#[inline]
+pub fn GenTexture(texture: &mut u32) {
+    GenTextures(std::slice::from_mut(texture));
+}
+/// # GL Invariants
+/// length of `textures` >= 0
+///
+/// # Panics
+/// This will panic if you pass more than `i32::MAX` textures at once.
+/// You don't need that many
+#[inline]
+pub fn DeleteTextures(textures: &[u32]) {
+    // SAFETY: the pointer to the slice is aligned, and the read is constrained by its length
+    unsafe {
+        gl::DeleteTextures(i32::try_from(textures.len()).expect("number of textures > i32::MAX"),
+            textures.as_ptr());
+    }
+}
+#[inline]
+pub fn DeleteTexture(texture: u32) {
+    DeleteTextures(&[texture]);
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TextureTarget {
+    Texture2D,
+}
+/// # GL Invariants
+/// target: accepted value (GLenum)
+///
+/// # User Invariants
+/// `texture`: is a valid texture returned by `glGenTextures` or 0
+/// `texture`: was created with, or not yet bound to, a different target
+///
+/// # Errors
+/// `GL_INVALID_VALUE`: `texture` was not returned by `glGenTextures`, 0 or was deleted
+/// `GL_INVALID_OPERATON`: `texture` was created with a different target
+#[inline]
+pub fn BindTexture(target: TextureTarget, texture: u32) {
+    // SAFETY: FFI
+    unsafe { gl::BindTexture(target.into(), texture) };
+}
+/// # User Invariants
+/// unit: < `GL_MAX_COMBINED_TEXTURE_IMAGE_UNITS`, as the `GL_INVALID_ENUM` otherwise is not reported
+///
+/// # Panics
+/// This will panic if `GL_TEXTURE0` + unit overflows.
+#[inline]
+pub fn ActiveTexture(unit: u32) {
+    // SAFETY: FFI
+    unsafe { gl::ActiveTexture(gl::TEXTURE0.checked_add(unit).expect("texture unit overflows")) };
+}
+/// The sized formats textures store their texels in
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TextureFormat {
+    R8,
+    Rg8,
+    Rgb8,
+    Rgba8,
+    Srgb8Alpha8,
+    R16F,
+    Rg16F,
+    Rgba16F,
+    R32F,
+    Rg32F,
+    Rgba32F,
+    R32I,
+    R32UI,
+    Rgba32I,
+    Rgba32UI,
+    DepthComponent24,
+    DepthComponent32F,
+    Depth24Stencil8,
+}
+/// # GL Invariants
+/// target: accepted value (GLenum)
+/// internal format: accepted value (GLenum)
+/// levels, width, height >= 1
+///
+/// # User Invariants
+/// A texture must be bound to target, without immutable storage already
+/// levels <= log2(max(width, height)) + 1
+///
+/// # Errors
+/// `GL_INVALID_OPERATON`: no texture is bound, its storage is already immutable,
+///                        or there are too many levels for its size
+/// `GL_INVALID_VALUE`: width or height are 0, or > `GL_MAX_TEXTURE_SIZE`
+///
+/// # Panics
+/// This will panic if the levels, width or height > `i32::MAX`.
+#[inline]
+pub fn TexStorage2D(target: TextureTarget, levels: usize, internal_format: TextureFormat,
+    width: usize, height: usize) {
+    // SAFETY: FFI
+    unsafe {
+        gl::TexStorage2D(target.into(), i32::try_from(levels).expect("levels > i32::MAX"), internal_format.into(),
+            i32::try_from(width).expect("width > i32::MAX"), i32::try_from(height).expect("height > i32::MAX"));
+    }
+}
+/// Upload a block of pixels from the buffer bound to `GL_PIXEL_UNPACK_BUFFER` into the bound texture
+///
+/// # GL Invariants
+/// target: accepted value (GLenum)
+/// width, height >= 0
+/// format, data type: accepted values (GLenum)
+///
+/// # Safety
+/// A buffer must be bound to `GL_PIXEL_UNPACK_BUFFER`, otherwise `offset` is a pointer into client
+/// memory that OpenGL reads from
+///
+/// # User Invariants
+/// The unpack buffer must not be mapped
+/// The pixels read from `offset` must fit within the buffer, with `offset` a multiple of
+/// the data type's size
+/// The block must lie within the level, which the texture must have
+/// format and data type must be compatible with each other, and with the texture's format
+///
+/// # Notes
+/// `offset`: measured in bytes
+///
+/// # Errors
+/// `GL_INVALID_OPERATON`: format and data type are incompatible with each other or the texture,
+///                        the unpack buffer is mapped, the data would exceed the unpack buffer,
+///                        offset is misaligned for the data type
+/// `GL_INVALID_VALUE`: the block lies outside the level, or the level is invalid
+///
+/// # Panics
+/// This will panic if the level, width or height > `i32::MAX`.
+#[inline]
+#[allow(clippy::too_many_arguments)]
+pub unsafe fn TexSubImage2D(target: TextureTarget, level: usize, x: i32, y: i32, width: usize, height: usize,
+    format: PixelFormat, data_type: PixelDataType, offset: usize) {
+    // SAFETY: the caller guarantees a buffer is bound to GL_PIXEL_UNPACK_BUFFER,
+    //         so the pointer is an offset into it, which is never dereferenced
+    unsafe {
+        gl::TexSubImage2D(target.into(), i32::try_from(level).expect("level > i32::MAX"), x, y,
+            i32::try_from(width).expect("width > i32::MAX"), i32::try_from(height).expect("height > i32::MAX"),
+            format.into(), data_type.into(), offset as *const std::ffi::c_void);
+    }
+}
+/// Read a whole level of the bound texture into the buffer bound to `GL_PIXEL_PACK_BUFFER`
+///
+/// # GL Invariants
+/// target: accepted value (GLenum)
+/// format, data type: accepted values (GLenum)
+///
+/// # Safety
+/// A buffer must be bound to `GL_PIXEL_PACK_BUFFER`, otherwise `offset` is a pointer into client
+/// memory that OpenGL writes to
+///
+/// # User Invariants
+/// The pack buffer must not be mapped
+/// The pixels written at `offset` must fit within the buffer, with `offset` a multiple of
+/// the data type's size
+/// format and data type must be compatible with each other, and with the texture's format
+///
+/// # Notes
+/// `offset`: measured in bytes
+///
+/// # Errors
+/// `GL_INVALID_OPERATON`: format and data type are incompatible with each other or the texture,
+///                        the pack buffer is mapped, the data would exceed the pack buffer,
+///                        offset is misaligned for the data type
+/// `GL_INVALID_VALUE`: the level is invalid
+///
+/// # Panics
+/// This will panic if the level > `i32::MAX`.
+#[inline]
+pub unsafe fn GetTexImage(target: TextureTarget, level: usize, format: PixelFormat, data_type: PixelDataType,
+    offset: usize) {
+    // SAFETY: the caller guarantees a buffer is bound to GL_PIXEL_PACK_BUFFER,
+    //         so the pointer is an offset into it, which is never dereferenced
+    unsafe {
+        gl::GetTexImage(target.into(), i32::try_from(level).expect("level > i32::MAX"),
+            format.into(), data_type.into(), offset as *mut std::ffi::c_void);
+    }
+}
+
+pub use gl::types::GLsync;
+/// Create a fence, signalled once all previous commands have completed
+///
+/// # Errors
+/// None, as the condition and flags are fixed
+///
+/// # Notes
+/// returns a null sync object if the process fails
+#[inline]
+pub fn FenceSync() -> GLsync {
+    // SAFETY: FFI
+    unsafe {
+        gl::FenceSync(gl::SYNC_GPU_COMMANDS_COMPLETE, 0)
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum WaitStatus {
+    AlreadySignaled,
+    TimeoutExpired,
+    ConditionSatisfied,
+    WaitFailed,
+}
+/// # GL Invariants
+/// flags: `GL_SYNC_FLUSH_COMMANDS_BIT` or 0
+///
+/// # User Invariants
+/// sync: an existing sync object
+///
+/// # Notes
+/// `timeout`: measured in nanoseconds
+///
+/// # Errors
+/// `GL_INVALID_VALUE`: sync is not a sync object
+#[inline]
+pub fn ClientWaitSync(sync: GLsync, flush: bool, timeout: u64) -> WaitStatus {
+    // SAFETY: FFI, an invalid sync object generates an error rather than being dereferenced
+    let status = unsafe {
+        gl::ClientWaitSync(sync, if flush { gl::SYNC_FLUSH_COMMANDS_BIT } else { 0 }, timeout)
+    };
+    match status {
+        gl::ALREADY_SIGNALED => WaitStatus::AlreadySignaled,
+        gl::TIMEOUT_EXPIRED => WaitStatus::TimeoutExpired,
+        gl::CONDITION_SATISFIED => WaitStatus::ConditionSatisfied,
+        _ => WaitStatus::WaitFailed,
+    }
+}
+/// # User Invariants
+/// sync: an existing sync object, or null
+///
+/// # Errors
+/// `GL_INVALID_VALUE`: sync is neither a sync object nor null
+#[inline]
+pub fn DeleteSync(sync: GLsync) {
+    // SAFETY: FFI, an invalid sync object generates an error rather than being dereferenced
+    unsafe {
+        gl::DeleteSync(sync);
+    }
+}
+
+bitflags! (
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    pub struct MemoryBarrierFlags: u32 {
+        const VertexAttribArray = 1;
+        const ElementArray = 1 << 1;
+        const Uniform = 1 << 2;
+        const Command = 1 << 3;
+        const PixelBuffer = 1 << 4;
+        const BufferUpdate = 1 << 5;
+        const ShaderStorage = 1 << 6;
+        const AtomicCounter = 1 << 7;
+        const ClientMappedBuffer = 1 << 8;
+    }
+);
+impl From<MemoryBarrierFlags> for gl::types::GLbitfield {
+    fn from(val: MemoryBarrierFlags) -> Self {
+        let mut bitfield = 0;
+        if val.contains(MemoryBarrierFlags::VertexAttribArray) {
+            bitfield |= gl::VERTEX_ATTRIB_ARRAY_BARRIER_BIT;
+        }
+        if val.contains(MemoryBarrierFlags::ElementArray) {
+            bitfield |= gl::ELEMENT_ARRAY_BARRIER_BIT;
+        }
+        if val.contains(MemoryBarrierFlags::Uniform) {
+            bitfield |= gl::UNIFORM_BARRIER_BIT;
+        }
+        if val.contains(MemoryBarrierFlags::Command) {
+            bitfield |= gl::COMMAND_BARRIER_BIT;
+        }
+        if val.contains(MemoryBarrierFlags::PixelBuffer) {
+            bitfield |= gl::PIXEL_BUFFER_BARRIER_BIT;
+        }
+        if val.contains(MemoryBarrierFlags::BufferUpdate) {
+            bitfield |= gl::BUFFER_UPDATE_BARRIER_BIT;
+        }
+        if val.contains(MemoryBarrierFlags::ShaderStorage) {
+            bitfield |= gl::SHADER_STORAGE_BARRIER_BIT;
+        }
+        if val.contains(MemoryBarrierFlags::AtomicCounter) {
+            bitfield |= gl::ATOMIC_COUNTER_BARRIER_BIT;
+        }
+        if val.contains(MemoryBarrierFlags::ClientMappedBuffer) {
+            bitfield |= gl::CLIENT_MAPPED_BUFFER_BARRIER_BIT;
+        }
+        bitfield
+    }
+}
+/// # GL Invariants
+/// barriers: accepted bits (`GLbitfield`)
+///
+/// # Notes
+/// Orders shader writes to memory, e.g. through atomic counters or shader storage,
+/// before the accesses of the given kinds issued after it
+#[inline]
+pub fn MemoryBarrier(barriers: MemoryBarrierFlags) {
+    // SAFETY: FFI
+    unsafe {
+        gl::MemoryBarrier(barriers.into());
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum DataType {
     Byte,
@@ -802,6 +1794,24 @@ impl From<BufferType> for gl::types::GLenum {
         }
     }
 } 
+impl From<BufferParameter> for gl::types::GLenum {
+    fn from(val: BufferParameter) -> Self {
+        match val {
+            BufferParameter::Size => gl::BUFFER_SIZE,
+            BufferParameter::Mapped => gl::BUFFER_MAPPED,
+        }
+    }
+}
+impl From<IndexedBufferType> for gl::types::GLenum {
+    fn from(val: IndexedBufferType) -> Self {
+        match val {
+            IndexedBufferType::AtomicCounter => gl::ATOMIC_COUNTER_BUFFER,
+            IndexedBufferType::ShaderStorage => gl::SHADER_STORAGE_BUFFER,
+            IndexedBufferType::TransformFeedback => gl::TRANSFORM_FEEDBACK_BUFFER,
+            IndexedBufferType::Uniform => gl::UNIFORM_BUFFER,
+        }
+    }
+}
 impl From<BufferUsage> for gl::types::GLenum {
     fn from(val: BufferUsage) -> Self {
         match val {
@@ -838,6 +1848,8 @@ impl From<Parameter> for gl::types::GLenum {
             Parameter::ArrayBufferBinding => gl::ARRAY_BUFFER_BINDING,
             Parameter::ElementBufferBinding => gl::ELEMENT_ARRAY_BUFFER_BINDING,
             Parameter::MaxComputeShaderStorageBlocks => gl::MAX_COMPUTE_SHADER_STORAGE_BLOCKS,
+            Parameter::MaxUniformBufferBindings => gl::MAX_UNIFORM_BUFFER_BINDINGS,
+            Parameter::MaxCombinedTextureImageUnits => gl::MAX_COMBINED_TEXTURE_IMAGE_UNITS,
         }
     }
 }
@@ -981,6 +1993,74 @@ impl From<IndexType> for gl::types::GLenum {
         }
     }
 }
+impl From<PixelFormat> for gl::types::GLenum {
+    fn from(val: PixelFormat) -> Self {
+        match val {
+            PixelFormat::Red => gl::RED,
+            PixelFormat::Green => gl::GREEN,
+            PixelFormat::Blue => gl::BLUE,
+            PixelFormat::Rg => gl::RG,
+            PixelFormat::Rgb => gl::RGB,
+            PixelFormat::Bgr => gl::BGR,
+            PixelFormat::Rgba => gl::RGBA,
+            PixelFormat::Bgra => gl::BGRA,
+            PixelFormat::RedInteger => gl::RED_INTEGER,
+            PixelFormat::RgInteger => gl::RG_INTEGER,
+            PixelFormat::RgbInteger => gl::RGB_INTEGER,
+            PixelFormat::RgbaInteger => gl::RGBA_INTEGER,
+            PixelFormat::DepthComponent => gl::DEPTH_COMPONENT,
+            PixelFormat::StencilIndex => gl::STENCIL_INDEX,
+            PixelFormat::DepthStencil => gl::DEPTH_STENCIL,
+        }
+    }
+}
+impl From<PixelDataType> for gl::types::GLenum {
+    fn from(val: PixelDataType) -> Self {
+        match val {
+            PixelDataType::UnsignedByte => gl::UNSIGNED_BYTE,
+            PixelDataType::Byte => gl::BYTE,
+            PixelDataType::UnsignedShort => gl::UNSIGNED_SHORT,
+            PixelDataType::Short => gl::SHORT,
+            PixelDataType::UnsignedInt => gl::UNSIGNED_INT,
+            PixelDataType::Int => gl::INT,
+            PixelDataType::HalfFloat => gl::HALF_FLOAT,
+            PixelDataType::Float => gl::FLOAT,
+            PixelDataType::UnsignedInt24_8 => gl::UNSIGNED_INT_24_8,
+            PixelDataType::Float32UnsignedInt24_8Rev => gl::FLOAT_32_UNSIGNED_INT_24_8_REV,
+        }
+    }
+}
+impl From<TextureTarget> for gl::types::GLenum {
+    fn from(val: TextureTarget) -> Self {
+        match val {
+            TextureTarget::Texture2D => gl::TEXTURE_2D,
+        }
+    }
+}
+impl From<TextureFormat> for gl::types::GLenum {
+    fn from(val: TextureFormat) -> Self {
+        match val {
+            TextureFormat::R8 => gl::R8,
+            TextureFormat::Rg8 => gl::RG8,
+            TextureFormat::Rgb8 => gl::RGB8,
+            TextureFormat::Rgba8 => gl::RGBA8,
+            TextureFormat::Srgb8Alpha8 => gl::SRGB8_ALPHA8,
+            TextureFormat::R16F => gl::R16F,
+            TextureFormat::Rg16F => gl::RG16F,
+            TextureFormat::Rgba16F => gl::RGBA16F,
+            TextureFormat::R32F => gl::R32F,
+            TextureFormat::Rg32F => gl::RG32F,
+            TextureFormat::Rgba32F => gl::RGBA32F,
+            TextureFormat::R32I => gl::R32I,
+            TextureFormat::R32UI => gl::R32UI,
+            TextureFormat::Rgba32I => gl::RGBA32I,
+            TextureFormat::Rgba32UI => gl::RGBA32UI,
+            TextureFormat::DepthComponent24 => gl::DEPTH_COMPONENT24,
+            TextureFormat::DepthComponent32F => gl::DEPTH_COMPONENT32F,
+            TextureFormat::Depth24Stencil8 => gl::DEPTH24_STENCIL8,
+        }
+    }
+}
 impl std::fmt::Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let s = match self {
@@ -1,6 +1,6 @@
 use std::ffi::CString;
 
-use crate::{IntegralVertexFormat, FloatVertexFormat, AttributePointer, OwlError, VertexArray, DataTypeUnsized, DataTypeSize3, DataTypeSize4};
+use crate::{IntegralVertexFormat, FloatVertexFormat, DoubleVertexFormat, AttributePointer, OwlError, VertexArray, DataTypeUnsized, DataTypeSize3, DataTypeSize4, ShaderStorageBuffer, AtomicCounterBuffer};
 use crate::prelude::*;
 use crate::ox;
 
@@ -9,6 +9,7 @@ use crate::ox;
 pub enum AttributeType {
     Bool,
     Int,
+    UInt,
     Float,
     Vec2,
     Vec3,
@@ -22,15 +23,23 @@ pub enum AttributeType {
     UVec2,
     UVec3,
     UVec4,
+    Double,
+    DVec2,
+    DVec3,
+    DVec4,
     Mat2,
     Mat3,
-    Mat4
+    Mat4,
+    DMat2,
+    DMat3,
+    DMat4,
 }
 impl std::fmt::Display for AttributeType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let s = match self {
             Self::Bool => "bool",
             Self::Int => "int",
+            Self::UInt => "uint",
             Self::Float => "float",
             Self::Vec2 => "vec2",
             Self::Vec3 => "vec3",
@@ -44,9 +53,16 @@ impl std::fmt::Display for AttributeType {
             Self::UVec2 => "uvec2",
             Self::UVec3 => "uvec3",
             Self::UVec4 => "uvec4",
+            Self::Double => "double",
+            Self::DVec2 => "dvec2",
+            Self::DVec3 => "dvec3",
+            Self::DVec4 => "dvec4",
             Self::Mat2 => "mat2",
             Self::Mat3 => "mat3",
             Self::Mat4 => "mat4",
+            Self::DMat2 => "dmat2",
+            Self::DMat3 => "dmat3",
+            Self::DMat4 => "dmat4",
         };    
         write!(f, "{s}")
     }
@@ -83,6 +99,7 @@ impl From<ThinFloatAttributeType> for AttributeType {
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum IntegralAttributeType {
     Int,
+    UInt,
     IVec2,
     IVec3,
     IVec4,
@@ -94,6 +111,7 @@ impl From<IntegralAttributeType> for AttributeType {
     fn from(value: IntegralAttributeType) -> Self {
         match value {
             IntegralAttributeType::Int => Self::Int,
+            IntegralAttributeType::UInt => Self::UInt,
             IntegralAttributeType::IVec2 => Self::IVec2,
             IntegralAttributeType::IVec3 => Self::IVec3,
             IntegralAttributeType::IVec4 => Self::IVec4,
@@ -104,6 +122,25 @@ impl From<IntegralAttributeType> for AttributeType {
     }
 }
 
+/// Corresponds to a double precision glsl type
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum DoubleAttributeType {
+    Double,
+    DVec2,
+    DVec3,
+    DVec4,
+}
+impl From<DoubleAttributeType> for AttributeType {
+    fn from(value: DoubleAttributeType) -> Self {
+        match value {
+            DoubleAttributeType::Double => Self::Double,
+            DoubleAttributeType::DVec2 => Self::DVec2,
+            DoubleAttributeType::DVec3 => Self::DVec3,
+            DoubleAttributeType::DVec4 => Self::DVec4,
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum AttributeLength {
     Single,
@@ -128,6 +165,8 @@ pub enum InputAttribute<'a, T: ToByteVec> {
 pub enum ThinInputAttribute {
     Integral { name: String, glsl_type: IntegralAttributeType, data_format: IntegralVertexFormat },
     Float { name: String, glsl_type: ThinFloatAttributeType, data_format: FloatVertexFormat },
+    /// Doubles, input at full precision, rather than converted to floats
+    Double { name: String, glsl_type: DoubleAttributeType, data_format: DoubleVertexFormat },
 }
 impl From<ThinInputAttribute> for Attribute {
     fn from(value: ThinInputAttribute) -> Self {
@@ -136,22 +175,61 @@ impl From<ThinInputAttribute> for Attribute {
                 Self { name, glsl_type: glsl_type.into(), length: AttributeLength::Single },
             ThinInputAttribute::Float { name, glsl_type, .. } =>
                 Self { name, glsl_type: glsl_type.into(), length: AttributeLength::Single  },
+            ThinInputAttribute::Double { name, glsl_type, .. } =>
+                Self { name, glsl_type: glsl_type.into(), length: AttributeLength::Single  },
+        }
+    }
+}
+impl ThinInputAttribute {
+    /// The number of consecutive locations the input takes, more than one only for a `dvec3` or `dvec4`
+    pub(crate) const fn locations(&self) -> u8 {
+        match self {
+            Self::Integral { .. } | Self::Float { .. } => 1,
+            Self::Double { data_format, .. } => data_format.locations(),
         }
     }
 }
 
+/// A matrix input, whose columns are input at consecutive locations, read from `pointer`
+/// and each following column's offset in turn, as `[[f32; 4]; 4]` and other column-major matrices are laid out
+///
+/// Each column of a `dmat3` or `dmat4` takes a pair of locations, as a `dvec3` or `dvec4` does.
 #[derive(Debug, Clone)] // not necessarily unique, could be on different buffers ==> not Eq, Hash, etc...
 pub enum MatInputAttributePointer<'a, T: ToByteVec> {
-    Mat2 { name: String, normalise: bool, pointers: [AttributePointer<'a,T>;2], data_type: DataTypeUnsized },
-    Mat3 { name: String, normalise: bool, pointers: [AttributePointer<'a,T>;3], data_type: DataTypeSize3 },
-    Mat4 { name: String, normalise: bool, pointers: [AttributePointer<'a,T>;4], data_type: DataTypeSize4 }
+    Mat2 { name: String, normalise: bool, pointer: AttributePointer<'a,T>, data_type: DataTypeUnsized },
+    Mat3 { name: String, normalise: bool, pointer: AttributePointer<'a,T>, data_type: DataTypeSize3 },
+    Mat4 { name: String, normalise: bool, pointer: AttributePointer<'a,T>, data_type: DataTypeSize4 },
+    /// Doubles, input at full precision, as for [`ThinInputAttribute::Double`]
+    DMat2 { name: String, pointer: AttributePointer<'a,T> },
+    DMat3 { name: String, pointer: AttributePointer<'a,T> },
+    DMat4 { name: String, pointer: AttributePointer<'a,T> },
 }
 impl<'a, T: ToByteVec> MatInputAttributePointer<'a, T> {
+    /// The number of columns
     pub(crate) const fn size(&self) -> u8 {
         match self {
-            Self::Mat2 { .. } => 2,
-            Self::Mat3 { .. } => 3,
-            Self::Mat4 { .. } => 4,
+            Self::Mat2 { .. } | Self::DMat2 { .. } => 2,
+            Self::Mat3 { .. } | Self::DMat3 { .. } => 3,
+            Self::Mat4 { .. } | Self::DMat4 { .. } => 4,
+        }
+    }
+    /// The number of consecutive locations each column takes, 2 for a `dmat3` or `dmat4`,
+    /// as for a `dvec3` or `dvec4`
+    pub(crate) const fn column_locations(&self) -> u8 {
+        match self {
+            Self::DMat3 { .. } | Self::DMat4 { .. } => 2,
+            _ => 1,
+        }
+    }
+    /// The number of consecutive locations the matrix takes
+    pub(crate) const fn locations(&self) -> u8 {
+        self.size() * self.column_locations()
+    }
+    /// The pointer to the first column
+    pub(crate) const fn pointer(&self) -> &AttributePointer<'a, T> {
+        match self {
+            Self::Mat2 { pointer, .. } | Self::Mat3 { pointer, .. } | Self::Mat4 { pointer, .. }
+                | Self::DMat2 { pointer, .. } | Self::DMat3 { pointer, .. } | Self::DMat4 { pointer, .. } => pointer,
         }
     }
 }
@@ -161,6 +239,9 @@ impl<'a, T: ToByteVec> From<MatInputAttributePointer<'a, T>> for Attribute {
             MatInputAttributePointer::Mat2 { name, .. } => Self { name, glsl_type: AttributeType::Mat2, length: AttributeLength::Single  },
             MatInputAttributePointer::Mat3 { name, .. } => Self { name, glsl_type: AttributeType::Mat3, length: AttributeLength::Single  },
             MatInputAttributePointer::Mat4 { name, .. } => Self { name, glsl_type: AttributeType::Mat4, length: AttributeLength::Single  },
+            MatInputAttributePointer::DMat2 { name, .. } => Self { name, glsl_type: AttributeType::DMat2, length: AttributeLength::Single  },
+            MatInputAttributePointer::DMat3 { name, .. } => Self { name, glsl_type: AttributeType::DMat3, length: AttributeLength::Single  },
+            MatInputAttributePointer::DMat4 { name, .. } => Self { name, glsl_type: AttributeType::DMat4, length: AttributeLength::Single  },
         }
     }
 }
@@ -194,6 +275,11 @@ impl Input {
                     .expect("buffer should be bound, and index checked");
                 (name, glsl_type.into())
             },
+            ThinInputAttribute::Double { name, glsl_type, data_format } => {
+                ox::vertex_attrib_l_pointer(index, data_format, stride.into(), offset.into())
+                    .expect("buffer should be bound, and index checked");
+                (name, glsl_type.into())
+            },
         };
         Self {
             index, attribute: Attribute { name, glsl_type, length: AttributeLength::Single  }
@@ -232,50 +318,69 @@ impl Input {
                 }
                 (name, glsl_type.into())
             },
+            ThinInputAttribute::Double { name, glsl_type, data_format } => {
+                let datum_stride = data_format.size_bytes();
+                for i in 0..length {
+                    ox::vertex_attrib_l_pointer(index + i * data_format.locations(), data_format, stride.into(),
+                         usize::from(offset) + datum_stride * usize::from(i))
+                        .expect("buffer should be bound, and index checked");
+                }
+                (name, glsl_type.into())
+            },
         };
         Self {
             index, attribute: Attribute { name, glsl_type, length: AttributeLength::Array(usize::from(length)) }
         }
     }
+    /// Create a new matrix input, with a column at each location (or pair, for doubles) from `index`
     pub(crate) fn new_mat<T: ToByteVec>(index: u8, attribute: MatInputAttributePointer<T>) -> Self {
-        match attribute {
-            MatInputAttributePointer::Mat2 { name, normalise, pointers, data_type } => {
-                for p in pointers {
-                    p.buffer.bind();
-                    ox::vertex_attrib_pointer(index, FloatVertexFormat::Size2 { normalise, data_type },
-                        p.stride.into(), p.offset.into())
-                    .expect("buffer should be bound, and index checked");
-                }
-                Self {
-                    index, attribute: Attribute { name, glsl_type: AttributeType::Mat2, length: AttributeLength::Single  }
-                }
-            },
-            MatInputAttributePointer::Mat3 { name, normalise, pointers, data_type } => {
-                for p in pointers {
-                    p.buffer.bind();
-                    ox::vertex_attrib_pointer(index, FloatVertexFormat::Size3 { normalise, data_type },
-                        p.stride.into(), p.offset.into())
-                    .expect("buffer should be bound, and index checked");
-                }
-                Self {
-                    index, attribute: Attribute { name, glsl_type: AttributeType::Mat3, length: AttributeLength::Single  }
-                }
-            },
-            MatInputAttributePointer::Mat4 { name, normalise, pointers, data_type } => {
-                for p in pointers {
-                    p.buffer.bind();
-                    ox::vertex_attrib_pointer(index, FloatVertexFormat::Size4 { normalise, data_type },
-                        p.stride.into(), p.offset.into())
-                    .expect("buffer should be bound, and index checked");
-                }
-                Self {
-                    index, attribute: Attribute { name, glsl_type: AttributeType::Mat4, length: AttributeLength::Single  }
-                }
-            }
+        // square, so there are as many columns as rows in each
+        let columns = attribute.size();
+        let column_locations = attribute.column_locations();
+        let double_size = std::mem::size_of::<f64>();
+        let (name, glsl_type, column_format, datum_size, pointer) = match attribute {
+            MatInputAttributePointer::Mat2 { name, normalise, pointer, data_type } =>
+                (name, AttributeType::Mat2, ColumnFormat::Float(FloatVertexFormat::Size2 { normalise, data_type }),
+                    ox::DataType::from(data_type).size_bytes(), pointer),
+            MatInputAttributePointer::Mat3 { name, normalise, pointer, data_type } =>
+                (name, AttributeType::Mat3, ColumnFormat::Float(FloatVertexFormat::Size3 { normalise, data_type }),
+                    ox::DataType::from(data_type).size_bytes(), pointer),
+            MatInputAttributePointer::Mat4 { name, normalise, pointer, data_type } =>
+                (name, AttributeType::Mat4, ColumnFormat::Float(FloatVertexFormat::Size4 { normalise, data_type }),
+                    ox::DataType::from(data_type).size_bytes(), pointer),
+            MatInputAttributePointer::DMat2 { name, pointer } =>
+                (name, AttributeType::DMat2, ColumnFormat::Double(DoubleVertexFormat::Size2), double_size, pointer),
+            MatInputAttributePointer::DMat3 { name, pointer } =>
+                (name, AttributeType::DMat3, ColumnFormat::Double(DoubleVertexFormat::Size3), double_size, pointer),
+            MatInputAttributePointer::DMat4 { name, pointer } =>
+                (name, AttributeType::DMat4, ColumnFormat::Double(DoubleVertexFormat::Size4), double_size, pointer),
+        };
+        let column_size = datum_size * usize::from(columns);
+        let AttributePointer { buffer, stride, offset } = pointer;
+        buffer.bind();
+        for column in 0..columns {
+            let column_offset = usize::from(offset) + column_size * usize::from(column);
+            let column_index = index + column * column_locations;
+            match column_format {
+                ColumnFormat::Float(data_format) =>
+                    ox::vertex_attrib_pointer(column_index, data_format, stride.into(), column_offset),
+                ColumnFormat::Double(data_format) =>
+                    ox::vertex_attrib_l_pointer(column_index, data_format, stride.into(), column_offset),
+            }.expect("buffer should be bound, and index checked");
+        }
+        Self {
+            index, attribute: Attribute { name, glsl_type, length: AttributeLength::Single  }
         }
     }
 }
 
+/// The format each column of a matrix input is read in, as floats or doubles
+#[derive(Debug, Clone, Copy)]
+enum ColumnFormat {
+    Float(FloatVertexFormat),
+    Double(DoubleVertexFormat),
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum PipeTargets {
     VertexFragment,
@@ -286,14 +391,33 @@ pub struct Pipe {
     pub attribute: Attribute,
 }
 
+/// The names with which a [`ShaderStorageBuffer`] is declared as a GLSL shader storage block
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct StorageBlock {
+    pub name: String,
+    pub binding: u32,
+    /// The name of the fixed part of the buffer, unused if it is `()`
+    pub header_name: String,
+    /// The name of the runtime-sized array, unused if it is of `()`
+    pub elements_name: String,
+}
+
+/// The names with which the counters of an [`AtomicCounterBuffer`] are declared as GLSL `atomic_uint`s,
+/// in the order they are stored
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CounterDeclaration {
+    pub binding: u32,
+    pub names: Vec<String>,
+}
+
 /// A vertex shader and its (nul-terminated) source
-#[derive(Debug, Clone, Hash)]
+#[derive(Debug, Hash)]
 struct VertexShader {
     shader: ox::Shader,
     source: CString,
 }
 /// A fragment shader and its (nul-terminated) source
-#[derive(Debug, Clone, Hash)]
+#[derive(Debug, Hash)]
 struct FragmentShader {
     shader: ox::Shader,
     source: CString,
@@ -306,7 +430,7 @@ pub struct Program(ox::ShaderProgram);
 // INVARIANTS: only deleted on drop
 impl Program {
     pub(crate) fn use_self(&self) -> Result<(),OwlError> {
-        ox::use_program(self.0)
+        ox::use_program(&self.0)
             .map_err(|e| {
                 match e {
                     // cannot be deleted yet, so only possible error (I hope)
@@ -316,11 +440,36 @@ impl Program {
                 }.with_context("using program failed")
             })
     }
+    /// Link the uniform block `block_name` to the indexed uniform `binding` point, so that it
+    /// reads from whichever [`crate::UniformBuffer`] is bound there.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// * `block_name` contains nul bytes, or is not an active uniform block in the program; or
+    /// * `binding` >= `GL_MAX_UNIFORM_BUFFER_BINDINGS`.
+    pub fn bind_uniform_block(&self, block_name: &str, binding: u32) -> Result<(),OwlError> {
+        let name = CString::new(block_name)
+            .map_err(|_| OwlError::custom("block name contains nul bytes"))
+            .with_context("binding uniform block")?;
+        let block_index = ox::get_uniform_block_index(&self.0, &name)
+            .expect("program only deleted on drop")
+            .ok_or_else(|| OwlError::custom(&format!("no active uniform block named {block_name}")))
+            .with_context("binding uniform block")?;
+        ox::uniform_block_binding(&self.0, block_index, binding).map_err(|e| {
+            match e {
+                // program not deleted, and block index checked above
+                ox::OxError::BaseError(crate::OriginalError::InvalidValue) =>
+                    e.with_message("binding >= GL_MAX_UNIFORM_BUFFER_BINDINGS"),
+                _ => e.with_message("no other errors should be produced")
+            }.with_context("binding uniform block")
+        })
+    }
 }
 
 impl Drop for Program {
     fn drop(&mut self) {
-        ox::delete_program(self.0)
+        ox::delete_program(&self.0)
             .expect("program only deleted on drop");
     }
 }
@@ -334,6 +483,9 @@ pub struct ShaderPipeline {
     fragment: FragmentShader,
     inputs: Vec<Input>,
     pipes: Vec<Pipe>,
+    struct_definitions: Vec<String>,
+    storage_blocks: Vec<String>,
+    atomic_counters: Vec<String>,
 }
 
 impl ShaderPipeline {
@@ -357,9 +509,12 @@ impl ShaderPipeline {
                 output: Attribute { name: String::default(), glsl_type: AttributeType::Vec4, length: AttributeLength::Single  } },
             inputs: Vec::new(),
             pipes: Vec::new(),
+            struct_definitions: Vec::new(),
+            storage_blocks: Vec::new(),
+            atomic_counters: Vec::new(),
         })
     }
-    pub fn inputs_from_vertex_array<T: ToByteVec>(mut self, vertex_array: &VertexArray<T>) -> Self {
+    pub fn inputs_from_vertex_array<T: IndexElement>(mut self, vertex_array: &VertexArray<T>) -> Self {
         self.inputs = vertex_array.inputs.container.clone();
         self
     }
@@ -384,6 +539,47 @@ impl ShaderPipeline {
         self.pipes.push(pipe);
         self
     }
+    /// Bind `buffer` to `block.binding`, and declare it (and any structs it contains) in every shader
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// * the buffer has neither a fixed part, nor a runtime-sized array; or
+    /// * `block.binding` >= `GL_MAX_SHADER_STORAGE_BUFFER_BINDINGS`.
+    pub fn storage_block<H: Std430, E: Std430>(mut self, buffer: &ShaderStorageBuffer<H, E>, block: &StorageBlock) -> Result<Self,OwlError> {
+        let header = (H::STD430_SIZE > 0)
+            .then(|| format!("    {} {};\n", H::glsl_type(), block.header_name));
+        let elements = (E::STD430_SIZE > 0)
+            .then(|| format!("    {} {}[];\n", E::glsl_type(), block.elements_name));
+        if header.is_none() && elements.is_none() {
+            return Err(OwlError::custom("storage block must have a fixed part or runtime-sized array"))
+                .with_context("adding storage block to pipeline");
+        }
+        buffer.bind_to(block.binding).with_context("adding storage block to pipeline")?;
+        H::glsl_definitions(&mut self.struct_definitions);
+        E::glsl_definitions(&mut self.struct_definitions);
+        self.storage_blocks.push(format!("layout (std430, binding = {}) buffer {} {{\n{}{}}};\n",
+            block.binding, block.name, header.unwrap_or_default(), elements.unwrap_or_default()));
+        Ok(self)
+    }
+    /// Bind `buffer` to `counters.binding`, and declare each of its counters in every shader
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// * there are more names than counters in the buffer; or
+    /// * `counters.binding` >= `GL_MAX_ATOMIC_COUNTER_BUFFER_BINDINGS`.
+    pub fn atomic_counters(mut self, buffer: &AtomicCounterBuffer, counters: &CounterDeclaration) -> Result<Self,OwlError> {
+        if counters.names.len() > buffer.len() {
+            return Err(OwlError::custom("more counter names than counters"))
+                .with_context("adding atomic counters to pipeline");
+        }
+        buffer.bind_to(counters.binding).with_context("adding atomic counters to pipeline")?;
+        self.atomic_counters.extend(counters.names.iter().enumerate().map(|(i, name)|
+            format!("layout (binding = {}, offset = {}) uniform atomic_uint {name};\n",
+                counters.binding, i * std::mem::size_of::<u32>())));
+        Ok(self)
+    }
     /// # Errors
     ///
     /// This function will return an error if:
@@ -391,7 +587,8 @@ impl ShaderPipeline {
     /// * a new shader program cannot be created.
     pub fn compile(self) -> Result<Program,OwlError> {
         // add inputs to vertex code
-        let version_prelude = format!("#version {} core\n", self.version);
+        let version_prelude = format!("#version {} core\n", self.version)
+            + &self.struct_definitions.concat() + &self.storage_blocks.concat() + &self.atomic_counters.concat();
         let vertex_source = {
             let input_to_glsl = |i: &Input| {
                 let array_qualifier = match i.attribute.length {
@@ -440,19 +637,19 @@ impl ShaderPipeline {
         };
         // println!("{}", fragment_source.clone().into_string().unwrap());
         // compile shaders
-        ox::shader_source(self.vertex.shader, &[vertex_source]).and(
-            ox::shader_source(self.fragment.shader, &[fragment_source]))
+        ox::shader_source(&self.vertex.shader, &[vertex_source]).and(
+            ox::shader_source(&self.fragment.shader, &[fragment_source]))
             .expect("shaders not yet deleted");
         // shaders not yet deleted, so only ShaderErrors
-        ox::compile_shader(self.vertex.shader).with_context("compiling pipeline (vertex shader)")?;
-        ox::compile_shader(self.fragment.shader).with_context("compiling pipeline (fragment shader)")?;
+        ox::compile_shader(&self.vertex.shader).with_context("compiling pipeline (vertex shader)")?;
+        ox::compile_shader(&self.fragment.shader).with_context("compiling pipeline (fragment shader)")?;
         // link program
         let program = ox::create_program().with_context("compiling pipeline (shader program)")?;
-        ox::attach_shader(program, self.vertex.shader)
+        ox::attach_shader(&program, &self.vertex.shader)
             .expect("shader is neither deleted, nor already attached");
-        ox::attach_shader(program, self.fragment.shader)
+        ox::attach_shader(&program, &self.fragment.shader)
             .expect("shader is neither deleted, nor already attached");
-        ox::link_program(program)
+        ox::link_program(&program)
             .expect("program has not been deleted, is not active, nor is in transform feedback mode");
         ox::delete_shader(self.vertex.shader)
             .expect("shader is not deleted");
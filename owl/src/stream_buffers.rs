@@ -0,0 +1,165 @@
+use std::collections::VecDeque;
+use std::ops::Range;
+use crate::ox;
+use crate::traits::{Bytes, Pod};
+use crate::{ArrayBuffer, BufferStorageFlags, OwlError, ToOwlError};
+
+/// How long to block on a fence before checking it again, in nanoseconds
+const FENCE_TIMEOUT: u64 = 1_000_000_000;
+
+/// A region of a [`StreamBuffer`] that data was pushed to
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct StreamRange {
+    /// The index of the first element, e.g. for [`crate::Mesh::start`] when the vertex array's
+    /// inputs point at the start of the buffer
+    pub start: usize,
+    /// The number of elements, e.g. for [`crate::Mesh::count`]
+    pub count: usize,
+    /// The offset of the first element, e.g. for [`crate::AttributePointer::offset`]
+    pub offset: Bytes,
+}
+
+/// A ring of persistently mapped vertex data, for data rewritten every frame.
+///
+/// Data is pushed into the ring without rebinding or stalling the pipeline; once the draws
+/// reading it have been issued, [`StreamBuffer::fence`] marks it, so that the ring waits for
+/// the GPU to finish reading it before writing over it again.
+#[derive(Debug)]
+pub struct StreamBuffer<T: Pod> {
+    buffer: ArrayBuffer<T>,
+    capacity: usize,
+    /// The index the next push starts from, unless it wraps
+    head: usize,
+    /// The regions pushed since the last fence
+    unfenced: Vec<Range<usize>>,
+    /// The regions pushed before each fence, oldest first
+    fenced: VecDeque<(ox::Fence, Vec<Range<usize>>)>,
+}
+impl<T: Pod> StreamBuffer<T> {
+    /// Create a ring of `capacity` elements
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the capacity is 0, or we are out of memory.
+    pub fn new(capacity: usize) -> Result<Self, OwlError> {
+        // SAFETY: T is Pod, so valid for any bit pattern, including zero
+        let zeroed: T = unsafe { std::mem::zeroed() };
+        let buffer = ArrayBuffer::immutable_from_slice(&vec![zeroed; capacity],
+            BufferStorageFlags::MapWrite | BufferStorageFlags::MapPersistent | BufferStorageFlags::MapCoherent)
+            .with_context("creating StreamBuffer")?;
+        Ok(Self { buffer, capacity, head: 0, unfenced: Vec::new(), fenced: VecDeque::new() })
+    }
+    /// The underlying buffer, for the [`crate::AttributePointer`]s of vertex array inputs
+    #[must_use]
+    pub const fn buffer(&self) -> &ArrayBuffer<T> {
+        &self.buffer
+    }
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+    /// Write `data` into the next free region of the ring, waiting for the GPU to finish reading
+    /// any earlier data there.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `data` is longer than the ring, if it would overwrite
+    /// data pushed since the last [`StreamBuffer::fence`], or if waiting on a fence fails.
+    pub fn push(&mut self, data: &[T]) -> Result<StreamRange, OwlError> {
+        if data.len() > self.capacity {
+            return Err(OwlError::custom("data length > stream buffer capacity"))
+                .with_context("pushing to StreamBuffer");
+        }
+        let start = if self.head + data.len() > self.capacity { 0 } else { self.head };
+        let region = start..start + data.len();
+        if self.unfenced.iter().any(|unfenced| overlaps(unfenced, &region)) {
+            return Err(OwlError::custom("data pushed since the last fence would be overwritten, the capacity is too small"))
+                .with_context("pushing to StreamBuffer");
+        }
+        self.wait_for(&region).with_context("pushing to StreamBuffer")?;
+        self.buffer.persistent_mut()
+            .expect("stream buffers are persistently mapped for writing")[region.clone()]
+            .copy_from_slice(data);
+        self.head = region.end;
+        self.unfenced.push(region);
+        Ok(StreamRange { start, count: data.len(), offset: Bytes(start * std::mem::size_of::<T>()) })
+    }
+    /// Mark the end of the draws reading the data pushed since the last fence,
+    /// typically once per frame
+    pub fn fence(&mut self) {
+        if !self.unfenced.is_empty() {
+            self.fenced.push_back((ox::fence_sync(), std::mem::take(&mut self.unfenced)));
+        }
+    }
+    /// Copy `length` elements of `source`, starting from `source_offset`, into this ring starting
+    /// from `destination_offset`, without a round trip through the CPU. Both regions are fenced,
+    /// so later pushes over them wait for the copy rather than racing it.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if either range overflows its ring.
+    pub fn copy_from(&mut self, source: &mut Self, source_offset: usize, destination_offset: usize,
+        length: usize) -> Result<(),OwlError> {
+        self.buffer.copy_from(&source.buffer, source_offset, destination_offset, length)
+            .with_context("copying to StreamBuffer")?;
+        source.fence_region(source_offset..source_offset + length);
+        self.fence_region(destination_offset..destination_offset + length);
+        Ok(())
+    }
+    /// A new ring with a copy of this one's contents, made without a round trip through the CPU,
+    /// e.g. to keep a frame's data while the ring moves on. Both rings are fenced, so pushes to
+    /// either wait for the copy rather than racing it.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if we are out of memory.
+    pub fn duplicate(&mut self) -> Result<Self, OwlError> {
+        let buffer = self.buffer.duplicate().with_context("duplicating StreamBuffer")?;
+        self.fence_region(0..self.capacity);
+        let mut duplicate = Self {
+            buffer, capacity: self.capacity, head: 0, unfenced: Vec::new(), fenced: VecDeque::new(),
+        };
+        duplicate.fence_region(0..self.capacity);
+        Ok(duplicate)
+    }
+    /// Fence `region` on its own, after the commands already issued, e.g. a copy touching it
+    fn fence_region(&mut self, region: Range<usize>) {
+        self.fenced.push_back((ox::fence_sync(), vec![region]));
+    }
+    /// Block until the GPU has finished with every fenced region overlapping `region`
+    ///
+    /// # Errors
+    /// This function will return an error if waiting on a fence fails.
+    fn wait_for(&mut self, region: &Range<usize>) -> Result<(),OwlError> {
+        // regions are fenced in order, so waiting on a fence means every earlier one is signalled too
+        let Some(newest_overlapping) = self.fenced.iter()
+            .rposition(|(_, fenced)| fenced.iter().any(|f| overlaps(f, region))) else {
+            return Ok(());
+        };
+        let (fence, _) = &self.fenced[newest_overlapping];
+        loop {
+            match ox::client_wait_sync(fence, true, FENCE_TIMEOUT) {
+                Ok(ox::WaitStatus::AlreadySignaled | ox::WaitStatus::ConditionSatisfied) => break,
+                // keep waiting, the GPU may just be busy
+                Ok(ox::WaitStatus::TimeoutExpired) => {},
+                Ok(ox::WaitStatus::WaitFailed) => return Err(OwlError::custom("waiting on fence failed")),
+                Err(e) => return Err(e.with_message("fence should not be deleted yet")),
+            }
+        }
+        for (fence, _) in self.fenced.drain(..=newest_overlapping) {
+            ox::delete_sync(fence);
+        }
+        Ok(())
+    }
+}
+impl<T: Pod> Drop for StreamBuffer<T> {
+    fn drop(&mut self) {
+        for (fence, _) in self.fenced.drain(..) {
+            ox::delete_sync(fence);
+        }
+    }
+}
+
+const fn overlaps(a: &Range<usize>, b: &Range<usize>) -> bool {
+    a.start < b.end && b.start < a.end
+}
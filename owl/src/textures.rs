@@ -0,0 +1,149 @@
+use crate::ox;
+use crate::traits::Pod;
+use crate::{OwlError, ToOwlError, PixelUnpackBuffer, PixelRectangle, PixelFormat, PixelDataType};
+pub use ox::TextureFormat;
+
+/// A two dimensional texture, with immutable storage for each of its mipmap levels.
+///
+/// Its pixels are uploaded from a [`PixelUnpackBuffer`], and read back into a [`crate::PixelPackBuffer`],
+/// so that both transfers overlap with rendering.
+#[derive(Debug)]
+pub struct Texture2D {
+    inner: ox::Texture,
+    width: usize,
+    height: usize,
+    levels: usize,
+    format: TextureFormat,
+}
+// INVARIANT: will not be deleted until it is dropped
+impl Texture2D {
+    /// Create a `width` by `height` texture with `levels` mipmap levels, each half the size of
+    /// the last, with undefined contents
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if there are no levels, or more than the size allows,
+    /// if the width or height is 0 or exceeds `GL_MAX_TEXTURE_SIZE`, or if we are out of memory.
+    pub fn new(width: usize, height: usize, levels: usize, format: TextureFormat) -> Result<Self, OwlError> {
+        let created = Self { inner: ox::gen_texture(), width, height, levels, format };
+        created.bind();
+        let allocated = ox::tex_storage_2d(ox::TextureTarget::Texture2D, levels, format, width, height).map_err(|e| {
+            match e {
+                ox::OxError::BaseError(crate::OriginalError::InvalidOperation) => {
+                    e.with_message("there are more levels than the size allows")
+                },
+                ox::OxError::BaseError(crate::OriginalError::InvalidValue) => {
+                    e.with_message("either there are no levels, or the width or height is 0 or exceeds \
+                        GL_MAX_TEXTURE_SIZE")
+                },
+                ox::OxError::BaseError(crate::OriginalError::OutOfMemory) => {
+                    e.with_message("out of memory")
+                },
+                _ => e.with_message("no other errors should be produced")
+            }
+            .with_context("creating Texture2D")
+        });
+        Self::unbind();
+        allocated.map(|()| created)
+    }
+    #[must_use]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+    #[must_use]
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+    /// The number of mipmap levels
+    #[must_use]
+    pub const fn levels(&self) -> usize {
+        self.levels
+    }
+    #[must_use]
+    pub const fn format(&self) -> TextureFormat {
+        self.format
+    }
+    /// The width and height of mipmap `level`
+    #[must_use]
+    pub fn level_size(&self, level: usize) -> (usize, usize) {
+        let halve = |size: usize| u32::try_from(level).ok()
+            .and_then(|level| size.checked_shr(level))
+            .unwrap_or(0)
+            .max(1);
+        (halve(self.width), halve(self.height))
+    }
+    /// Queue an upload of the `rectangle` of pixels in mipmap `level` from `buffer`, starting from
+    /// element `offset`, in the given format, without waiting for it to complete. The buffer may be
+    /// written again straight away, as the upload is ordered before any later writes.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the level does not exist, the rectangle lies outside it,
+    /// the pixels overflow the buffer, `offset` is misaligned for the data type, the format and data type
+    /// are incompatible with each other or the texture, or the buffer is being mapped.
+    pub fn upload_from<T: Pod>(&mut self, buffer: &PixelUnpackBuffer<T>, level: usize, rectangle: PixelRectangle,
+        format: PixelFormat, data_type: PixelDataType, offset: usize) -> Result<(),OwlError> {
+        self.check_level(level).with_context("uploading to Texture2D")?;
+        let (width, height) = self.level_size(level);
+        let within = |start: i32, length: usize, size: usize| usize::try_from(start)
+            .is_ok_and(|start| start + length <= size);
+        if !within(rectangle.x, rectangle.width, width) || !within(rectangle.y, rectangle.height, height) {
+            return Err(OwlError::custom("rectangle lies outside the level"))
+                .with_context("uploading to Texture2D");
+        }
+        buffer.bind();
+        self.bind();
+        // SAFETY: `buffer` was just bound to GL_PIXEL_UNPACK_BUFFER
+        let uploaded = unsafe { ox::tex_sub_image_2d(ox::TextureTarget::Texture2D, level, rectangle.x, rectangle.y,
+            rectangle.width, rectangle.height, format, data_type, offset * std::mem::size_of::<T>()) }.map_err(|e| {
+            match e {
+                ox::OxError::BaseError(crate::OriginalError::InvalidOperation) => {
+                    e.with_message("either the pixels overflow the buffer, the offset is misaligned, the format \
+                        and data type are incompatible, or the buffer is being mapped")
+                },
+                _ => e.with_message("no other errors should be produced")
+            }
+            .with_context("uploading to Texture2D")
+        });
+        // pixel transfers from client memory expect nothing to be bound
+        Self::unbind();
+        PixelUnpackBuffer::<T>::unbind();
+        uploaded
+    }
+    /// Bind the texture to texture `unit`, to be sampled by any sampler set to that unit
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `unit` >= `GL_MAX_COMBINED_TEXTURE_IMAGE_UNITS`.
+    pub fn bind_to(&self, unit: u32) -> Result<(),OwlError> {
+        if unit >= ox::get_uint(ox::UIntParameter::MaxCombinedTextureImageUnits) {
+            return Err(OwlError::custom("unit >= GL_MAX_COMBINED_TEXTURE_IMAGE_UNITS"))
+                .with_context("binding Texture2D");
+        }
+        ox::active_texture(unit);
+        self.bind();
+        Ok(())
+    }
+    /// # Errors
+    /// This function will return an error if the texture does not have mipmap `level`.
+    pub(crate) fn check_level(&self, level: usize) -> Result<(),OwlError> {
+        if level < self.levels {
+            Ok(())
+        } else {
+            Err(OwlError::custom("level >= texture levels"))
+        }
+    }
+    pub(crate) fn bind(&self) {
+        ox::bind_texture(ox::TextureTarget::Texture2D, Some(&self.inner))
+            .expect("texture should not be deleted yet, and is always 2D");
+    }
+    pub(crate) fn unbind() {
+        ox::bind_texture(ox::TextureTarget::Texture2D, None)
+            .expect("binding 0 always succeeds");
+    }
+}
+impl Drop for Texture2D {
+    fn drop(&mut self) {
+        ox::delete_texture(&self.inner);
+    }
+}
@@ -7,15 +7,40 @@ impl From<Bytes> for usize {
 }
 
 /// A trait to enable buffers to store data as byte representations
+///
+/// The layout of a type may also be known without a value, through its associated constants,
+/// which the derive provides for structs whose fields' layouts are all known.
 pub trait ToByteVec {
+    /// The number of bytes every value is written as, if it does not depend on the value
+    const STRIDE: Option<Bytes> = None;
+    /// The names of a struct's fields, tuple struct fields being named by index
+    const FIELD_NAMES: &'static [&'static str] = &[];
+    /// The offset of each of a struct's fields, if it does not depend on the value
+    const FIELD_OFFSETS: &'static [Option<Bytes>] = &[];
     fn to_byte_vec(self) -> Vec<u8>;
     fn stride(&self) -> Bytes;
     #[allow(unused_variables)]
     fn field_offset(&self, field_index: usize) -> Option<Bytes> {
         None
     }
+    /// The offset of field `N`, which fails to compile if it does not exist,
+    /// or depends on the value
+    #[must_use]
+    fn field_offset_of<const N: usize>() -> Bytes {
+        const {
+            assert!(N < Self::FIELD_OFFSETS.len(), "field index out of bounds");
+            Self::FIELD_OFFSETS[N].expect("field offset depends on the value")
+        }
+    }
+    /// The offset of the field named `name`, if it exists and does not depend on the value
+    #[must_use]
+    fn field_offset_named(name: &str) -> Option<Bytes> {
+        Self::FIELD_NAMES.iter().position(|field| *field == name)
+            .and_then(|index| Self::FIELD_OFFSETS.get(index).copied().flatten())
+    }
 }
 impl ToByteVec for u8 {
+    const STRIDE: Option<Bytes> = Some(Bytes(1));
     fn to_byte_vec(self) -> Vec<u8> {
         self.to_ne_bytes().to_vec()
     }
@@ -24,6 +49,7 @@ impl ToByteVec for u8 {
     }
 }
 impl ToByteVec for u16 {
+    const STRIDE: Option<Bytes> = Some(Bytes(2));
     fn to_byte_vec(self) -> Vec<u8> {
         self.to_ne_bytes().to_vec()
     }
@@ -32,6 +58,7 @@ impl ToByteVec for u16 {
     }
 }
 impl ToByteVec for u32 {
+    const STRIDE: Option<Bytes> = Some(Bytes(4));
     fn to_byte_vec(self) -> Vec<u8> {
         self.to_ne_bytes().to_vec()
     }
@@ -40,6 +67,7 @@ impl ToByteVec for u32 {
     }
 }
 impl ToByteVec for u64 {
+    const STRIDE: Option<Bytes> = Some(Bytes(8));
     fn to_byte_vec(self) -> Vec<u8> {
         self.to_ne_bytes().to_vec()
     }
@@ -48,6 +76,7 @@ impl ToByteVec for u64 {
     }
 }
 impl ToByteVec for u128 {
+    const STRIDE: Option<Bytes> = Some(Bytes(16));
     fn to_byte_vec(self) -> Vec<u8> {
         self.to_ne_bytes().to_vec()
     }
@@ -56,6 +85,7 @@ impl ToByteVec for u128 {
     }
 }
 impl ToByteVec for i8 {
+    const STRIDE: Option<Bytes> = Some(Bytes(1));
     fn to_byte_vec(self) -> Vec<u8> {
         self.to_ne_bytes().to_vec()
     }
@@ -64,6 +94,7 @@ impl ToByteVec for i8 {
     }
 }
 impl ToByteVec for i16 {
+    const STRIDE: Option<Bytes> = Some(Bytes(2));
     fn to_byte_vec(self) -> Vec<u8> {
         self.to_ne_bytes().to_vec()
     }
@@ -72,6 +103,7 @@ impl ToByteVec for i16 {
     }
 }
 impl ToByteVec for i32 {
+    const STRIDE: Option<Bytes> = Some(Bytes(4));
     fn to_byte_vec(self) -> Vec<u8> {
         self.to_ne_bytes().to_vec()
     }
@@ -80,6 +112,7 @@ impl ToByteVec for i32 {
     }
 }
 impl ToByteVec for i64 {
+    const STRIDE: Option<Bytes> = Some(Bytes(8));
     fn to_byte_vec(self) -> Vec<u8> {
         self.to_ne_bytes().to_vec()
     }
@@ -88,6 +121,7 @@ impl ToByteVec for i64 {
     }
 }
 impl ToByteVec for i128 {
+    const STRIDE: Option<Bytes> = Some(Bytes(16));
     fn to_byte_vec(self) -> Vec<u8> {
         self.to_ne_bytes().to_vec()
     }
@@ -96,6 +130,7 @@ impl ToByteVec for i128 {
     }
 }
 impl ToByteVec for f32 {
+    const STRIDE: Option<Bytes> = Some(Bytes(4));
     fn to_byte_vec(self) -> Vec<u8> {
         self.to_ne_bytes().to_vec()
     }
@@ -104,14 +139,16 @@ impl ToByteVec for f32 {
     }
 }
 impl ToByteVec for f64 {
+    const STRIDE: Option<Bytes> = Some(Bytes(8));
     fn to_byte_vec(self) -> Vec<u8> {
-        self.to_be_bytes().to_vec()
+        self.to_ne_bytes().to_vec()
     }
     fn stride(&self) -> Bytes {
         Bytes(8)
     }
 }
 impl ToByteVec for bool {
+    const STRIDE: Option<Bytes> = Some(Bytes(1));
     fn to_byte_vec(self) -> Vec<u8> {
         u8::from(self).to_byte_vec()
     }
@@ -120,6 +157,10 @@ impl ToByteVec for bool {
     }
 }
 impl<T: ToByteVec, const C: usize> ToByteVec for [T; C] {
+    const STRIDE: Option<Bytes> = match T::STRIDE {
+        Some(Bytes(stride)) => Some(Bytes(stride * C)),
+        None => None,
+    };
     fn to_byte_vec(self) -> Vec<u8> {
         self.into_iter().flat_map(ToByteVec::to_byte_vec).collect()
     }
@@ -145,6 +186,420 @@ impl<T: ToByteVec> ToByteVec for Vec<T> {
     }
 }
 
+/// The inverse of [`ToByteVec`], to read data back out of buffers.
+/// Structs may derive this, which requires every field to implement it too.
+pub trait FromByteVec: ToByteVec + Sized {
+    /// The number of bytes a value is read from, equal to its stride
+    const BYTE_SIZE: usize;
+    /// Read a value from the start of `bytes`
+    ///
+    /// # Panics
+    /// This will panic if `bytes` is shorter than [`FromByteVec::BYTE_SIZE`].
+    fn from_byte_slice(bytes: &[u8]) -> Self;
+    /// Read consecutive values from `bytes`, ignoring any incomplete value at the end
+    fn from_byte_vec(bytes: &[u8]) -> Vec<Self> {
+        if Self::BYTE_SIZE == 0 {
+            return Vec::new();
+        }
+        bytes.chunks_exact(Self::BYTE_SIZE).map(Self::from_byte_slice).collect()
+    }
+}
+macro_rules! impl_from_byte_vec {
+    ($($t:ty),*) => {
+        $(
+            impl FromByteVec for $t {
+                const BYTE_SIZE: usize = std::mem::size_of::<$t>();
+                fn from_byte_slice(bytes: &[u8]) -> Self {
+                    Self::from_ne_bytes(bytes[..Self::BYTE_SIZE].try_into()
+                        .expect("slice has the length of the array"))
+                }
+            }
+        )*
+    };
+}
+impl_from_byte_vec!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
+impl FromByteVec for bool {
+    const BYTE_SIZE: usize = 1;
+    fn from_byte_slice(bytes: &[u8]) -> Self {
+        bytes[0] != 0
+    }
+}
+impl<T: FromByteVec, const C: usize> FromByteVec for [T; C] {
+    const BYTE_SIZE: usize = T::BYTE_SIZE * C;
+    fn from_byte_slice(bytes: &[u8]) -> Self {
+        std::array::from_fn(|i| T::from_byte_slice(&bytes[i * T::BYTE_SIZE..]))
+    }
+}
+
+/// A marker for types whose bytes in memory are exactly those produced by
+/// [`ToByteVec::to_byte_vec`], so that mapped buffers can be viewed directly as slices of them.
+///
+/// # Safety
+/// Implementors must have no padding, lay their fields out in the order (and native byte order)
+/// that [`ToByteVec::to_byte_vec`] does, and be valid for any bit pattern.
+/// A `#[repr(C)]` struct of `Pod` fields, with no padding between them, satisfies this.
+pub unsafe trait Pod: ToByteVec + Copy {}
+macro_rules! impl_pod {
+    ($($t:ty),*) => {
+        $(
+            // SAFETY: primitives are written in native byte order, and valid for any bit pattern
+            unsafe impl Pod for $t {}
+        )*
+    };
+}
+impl_pod!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
+// SAFETY: arrays have no padding between their elements, which are written in order
+unsafe impl<T: Pod, const C: usize> Pod for [T; C] {}
+
+mod sealed {
+    pub trait Sealed {}
+    impl Sealed for u8 {}
+    impl Sealed for u16 {}
+    impl Sealed for u32 {}
+}
+/// The types that can index vertices in an [`crate::ElementBuffer`], which determine the index type
+/// it is drawn with. It is sealed, as OpenGL supports no others.
+pub trait IndexElement: sealed::Sealed + Pod + FromByteVec {
+    const INDEX_TYPE: crate::IndexType;
+}
+impl IndexElement for u8 {
+    const INDEX_TYPE: crate::IndexType = crate::IndexType::UnsignedByte;
+}
+impl IndexElement for u16 {
+    const INDEX_TYPE: crate::IndexType = crate::IndexType::UnsignedShort;
+}
+impl IndexElement for u32 {
+    const INDEX_TYPE: crate::IndexType = crate::IndexType::UnsignedInt;
+}
+
+/// A type whose fields are each an input to the vertex shader, so that a [`crate::VertexArray`] can
+/// take every input from an [`crate::ArrayBuffer`] of it at once.
+///
+/// Structs should derive this, alongside [`ToByteVec`], which requires every field to implement
+/// [`VertexField`].
+///
+/// # Notes
+/// Inputs are named after their fields, and integers and doubles are converted to `float`s, unless a field
+/// is marked with `#[owl(name = "...")]`, `#[owl(normalise)]`, `#[owl(integral)]`, requiring an
+/// [`IntegralVertexField`], or `#[owl(double)]`, requiring a [`DoubleVertexField`].
+///
+/// Vertices must all be the same size, as given by [`ToByteVec::STRIDE`].
+pub trait Vertex: ToByteVec {
+    /// Each field's input, and its offset within the vertex
+    fn inputs() -> Vec<(crate::ThinInputAttribute, Bytes)>;
+}
+/// The types a field of a [`Vertex`] can have: a scalar, or an array of 2 to 4 scalars
+pub trait VertexField: Pod {
+    /// The input of a field named `name`, as a `float` or `vecN`, converting integers to floating
+    /// point, normalised to [0,1] or [-1,1] if `normalise`
+    fn float_input(name: String, normalise: bool) -> crate::ThinInputAttribute;
+}
+/// The [`VertexField`]s of integers, which can be input as they are, rather than as floating point
+pub trait IntegralVertexField: VertexField {
+    /// The input of a field named `name`, as an `int`, `uint`, `ivecN` or `uvecN`
+    fn integral_input(name: String) -> crate::ThinInputAttribute;
+}
+/// The [`VertexField`]s of doubles, which can be input at full precision, rather than converted to floats
+pub trait DoubleVertexField: VertexField {
+    /// The input of a field named `name`, as a `double` or `dvecN`
+    fn double_input(name: String) -> crate::ThinInputAttribute;
+}
+macro_rules! impl_vertex_field {
+    ($($scalar:ty => $data_type:ident),*) => {$(
+        impl VertexField for $scalar {
+            fn float_input(name: String, normalise: bool) -> crate::ThinInputAttribute {
+                crate::ThinInputAttribute::Float { name, glsl_type: crate::ThinFloatAttributeType::Float,
+                    data_format: crate::FloatVertexFormat::Size1 { normalise, data_type: crate::DataTypeUnsized::$data_type } }
+            }
+        }
+        impl VertexField for [$scalar; 2] {
+            fn float_input(name: String, normalise: bool) -> crate::ThinInputAttribute {
+                crate::ThinInputAttribute::Float { name, glsl_type: crate::ThinFloatAttributeType::Vec2,
+                    data_format: crate::FloatVertexFormat::Size2 { normalise, data_type: crate::DataTypeUnsized::$data_type } }
+            }
+        }
+        impl VertexField for [$scalar; 3] {
+            fn float_input(name: String, normalise: bool) -> crate::ThinInputAttribute {
+                crate::ThinInputAttribute::Float { name, glsl_type: crate::ThinFloatAttributeType::Vec3,
+                    data_format: crate::FloatVertexFormat::Size3 { normalise, data_type: crate::DataTypeSize3::$data_type } }
+            }
+        }
+        impl VertexField for [$scalar; 4] {
+            fn float_input(name: String, normalise: bool) -> crate::ThinInputAttribute {
+                crate::ThinInputAttribute::Float { name, glsl_type: crate::ThinFloatAttributeType::Vec4,
+                    data_format: crate::FloatVertexFormat::Size4 { normalise, data_type: crate::DataTypeSize4::$data_type } }
+            }
+        }
+    )*};
+}
+impl_vertex_field!(f32 => Float, f64 => Double, i8 => Byte, u8 => UnsignedByte, i16 => Short,
+    u16 => UnsignedShort, i32 => Int, u32 => UnsignedInt);
+/// `$glsl` lists the scalar, then vector types the scalar is input as
+macro_rules! impl_integral_vertex_field {
+    ($($scalar:ty => $data_type:ident, [$glsl_1:ident, $glsl_2:ident, $glsl_3:ident, $glsl_4:ident]);*) => {$(
+        impl_integral_vertex_field!(@field $scalar, $data_type, $glsl_1, Size1);
+        impl_integral_vertex_field!(@field [$scalar; 2], $data_type, $glsl_2, Size2);
+        impl_integral_vertex_field!(@field [$scalar; 3], $data_type, $glsl_3, Size3);
+        impl_integral_vertex_field!(@field [$scalar; 4], $data_type, $glsl_4, Size4);
+    )*};
+    (@field $field:ty, $data_type:ident, $glsl:ident, $size:ident) => {
+        impl IntegralVertexField for $field {
+            fn integral_input(name: String) -> crate::ThinInputAttribute {
+                crate::ThinInputAttribute::Integral { name, glsl_type: crate::IntegralAttributeType::$glsl,
+                    data_format: crate::IntegralVertexFormat::$size(crate::IntegralDataType::$data_type) }
+            }
+        }
+    };
+}
+impl_integral_vertex_field!(
+    i8 => Byte, [Int, IVec2, IVec3, IVec4];
+    u8 => UnsignedByte, [UInt, UVec2, UVec3, UVec4];
+    i16 => Short, [Int, IVec2, IVec3, IVec4];
+    u16 => UnsignedShort, [UInt, UVec2, UVec3, UVec4];
+    i32 => Int, [Int, IVec2, IVec3, IVec4];
+    u32 => UnsignedInt, [UInt, UVec2, UVec3, UVec4]
+);
+macro_rules! impl_double_vertex_field {
+    ($($field:ty => $glsl:ident, $size:ident);*) => {$(
+        impl DoubleVertexField for $field {
+            fn double_input(name: String) -> crate::ThinInputAttribute {
+                crate::ThinInputAttribute::Double { name, glsl_type: crate::DoubleAttributeType::$glsl,
+                    data_format: crate::DoubleVertexFormat::$size }
+            }
+        }
+    )*};
+}
+impl_double_vertex_field!(f64 => Double, Size1; [f64; 2] => DVec2, Size2; [f64; 3] => DVec3, Size3;
+    [f64; 4] => DVec4, Size4);
+
+/// A trait to enable uniform buffers to store data in the `std140` layout.
+/// Structs should derive this, which requires every field to implement it too.
+///
+/// # Notes
+/// Arrays of 2, 3 and 4 scalars are laid out as vectors, and arrays of those as (column-major)
+/// matrices; GLSL arrays must instead be wrapped in a [`GlslArray`].
+pub trait Std140 {
+    /// The base alignment of the type, in bytes
+    const STD140_ALIGNMENT: usize;
+    /// The number of bytes occupied by the type, including any padding at the end of a struct
+    const STD140_SIZE: usize;
+    /// Append the `std140` representation of `self` to `bytes`, which must already be aligned to
+    /// [`Std140::STD140_ALIGNMENT`]
+    fn write_std140(&self, bytes: &mut Vec<u8>);
+    /// Read a value from the start of `bytes`, in the `std140` representation
+    ///
+    /// # Panics
+    /// This will panic if `bytes` is shorter than [`Std140::STD140_SIZE`].
+    fn read_std140(bytes: &[u8]) -> Self where Self: Sized;
+}
+/// A trait to enable shader storage buffers to store data in the `std430` layout, and to declare
+/// it in GLSL. Structs should derive this, which requires every field to implement it too.
+///
+/// # Notes
+/// As with [`Std140`], GLSL arrays must be wrapped in a [`GlslArray`].
+/// Only non-generic structs can derive it, as the GLSL struct is named after the rust one.
+pub trait Std430 {
+    /// The base alignment of the type, in bytes
+    const STD430_ALIGNMENT: usize;
+    /// The number of bytes occupied by the type, including any padding at the end of a struct
+    const STD430_SIZE: usize;
+    /// Append the `std430` representation of `self` to `bytes`, which must already be aligned to
+    /// [`Std430::STD430_ALIGNMENT`]
+    fn write_std430(&self, bytes: &mut Vec<u8>);
+    /// Read a value from the start of `bytes`, in the `std430` representation
+    ///
+    /// # Panics
+    /// This will panic if `bytes` is shorter than [`Std430::STD430_SIZE`].
+    fn read_std430(bytes: &[u8]) -> Self where Self: Sized;
+    /// The name of the corresponding GLSL type
+    fn glsl_type() -> String;
+    /// Append the GLSL definitions of any structs this type relies on, including itself, to
+    /// `definitions`, skipping those already present
+    #[allow(unused_variables)]
+    fn glsl_definitions(definitions: &mut Vec<String>) {}
+}
+macro_rules! impl_layouts_scalar {
+    ($($scalar:ty => $glsl:literal, $vector_prefix:literal);*) => {$(
+        impl Std140 for $scalar {
+            const STD140_ALIGNMENT: usize = std::mem::size_of::<$scalar>();
+            const STD140_SIZE: usize = std::mem::size_of::<$scalar>();
+            fn write_std140(&self, bytes: &mut Vec<u8>) {
+                bytes.extend(self.to_ne_bytes());
+            }
+            fn read_std140(bytes: &[u8]) -> Self {
+                <$scalar as FromByteVec>::from_byte_slice(bytes)
+            }
+        }
+        impl Std430 for $scalar {
+            const STD430_ALIGNMENT: usize = std::mem::size_of::<$scalar>();
+            const STD430_SIZE: usize = std::mem::size_of::<$scalar>();
+            fn write_std430(&self, bytes: &mut Vec<u8>) {
+                bytes.extend(self.to_ne_bytes());
+            }
+            fn read_std430(bytes: &[u8]) -> Self {
+                <$scalar as FromByteVec>::from_byte_slice(bytes)
+            }
+            fn glsl_type() -> String {
+                $glsl.to_owned()
+            }
+        }
+        impl_layouts_vector!($scalar, $vector_prefix, 2, 2);
+        impl_layouts_vector!($scalar, $vector_prefix, 3, 4);
+        impl_layouts_vector!($scalar, $vector_prefix, 4, 4);
+    )*};
+}
+/// A vector's alignment is that of `$alignment` components, vec3 being aligned as a vec4,
+/// and is the same in both layouts
+macro_rules! impl_layouts_vector {
+    ($scalar:ty, $prefix:literal, $length:literal, $alignment:literal) => {
+        impl Std140 for [$scalar; $length] {
+            const STD140_ALIGNMENT: usize = <$scalar as Std140>::STD140_ALIGNMENT * $alignment;
+            const STD140_SIZE: usize = <$scalar as Std140>::STD140_SIZE * $length;
+            fn write_std140(&self, bytes: &mut Vec<u8>) {
+                for component in self {
+                    component.write_std140(bytes);
+                }
+            }
+            fn read_std140(bytes: &[u8]) -> Self {
+                std::array::from_fn(|i| <$scalar as Std140>::read_std140(&bytes[i * <$scalar as Std140>::STD140_SIZE..]))
+            }
+        }
+        impl Std430 for [$scalar; $length] {
+            const STD430_ALIGNMENT: usize = <$scalar as Std430>::STD430_ALIGNMENT * $alignment;
+            const STD430_SIZE: usize = <$scalar as Std430>::STD430_SIZE * $length;
+            fn write_std430(&self, bytes: &mut Vec<u8>) {
+                for component in self {
+                    component.write_std430(bytes);
+                }
+            }
+            fn read_std430(bytes: &[u8]) -> Self {
+                std::array::from_fn(|i| <$scalar as Std430>::read_std430(&bytes[i * <$scalar as Std430>::STD430_SIZE..]))
+            }
+            fn glsl_type() -> String {
+                format!("{}vec{}", $prefix, $length)
+            }
+        }
+    };
+}
+impl_layouts_scalar!(f32 => "float", ""; f64 => "double", "d"; i32 => "int", "i"; u32 => "uint", "u");
+/// Booleans are stored as 32-bit values, as in GLSL
+impl Std140 for bool {
+    const STD140_ALIGNMENT: usize = 4;
+    const STD140_SIZE: usize = 4;
+    fn write_std140(&self, bytes: &mut Vec<u8>) {
+        u32::from(*self).write_std140(bytes);
+    }
+    fn read_std140(bytes: &[u8]) -> Self {
+        u32::read_std140(bytes) != 0
+    }
+}
+/// Booleans are stored as 32-bit values, as in GLSL
+impl Std430 for bool {
+    const STD430_ALIGNMENT: usize = 4;
+    const STD430_SIZE: usize = 4;
+    fn write_std430(&self, bytes: &mut Vec<u8>) {
+        u32::from(*self).write_std430(bytes);
+    }
+    fn read_std430(bytes: &[u8]) -> Self {
+        u32::read_std430(bytes) != 0
+    }
+    fn glsl_type() -> String {
+        "bool".to_owned()
+    }
+}
+impl_layouts_vector!(bool, "b", 2, 2);
+impl_layouts_vector!(bool, "b", 3, 4);
+impl_layouts_vector!(bool, "b", 4, 4);
+
+/// Matrices are stored as arrays of their column vectors
+macro_rules! impl_layouts_matrix {
+    ($scalar:ty, $prefix:literal; $($columns:literal x $rows:literal),*) => {$(
+        impl Std140 for [[$scalar; $rows]; $columns] {
+            const STD140_ALIGNMENT: usize = <GlslArray<[$scalar; $rows], $columns> as Std140>::STD140_ALIGNMENT;
+            const STD140_SIZE: usize = <GlslArray<[$scalar; $rows], $columns> as Std140>::STD140_SIZE;
+            fn write_std140(&self, bytes: &mut Vec<u8>) {
+                GlslArray(*self).write_std140(bytes);
+            }
+            fn read_std140(bytes: &[u8]) -> Self {
+                GlslArray::read_std140(bytes).0
+            }
+        }
+        impl Std430 for [[$scalar; $rows]; $columns] {
+            const STD430_ALIGNMENT: usize = <GlslArray<[$scalar; $rows], $columns> as Std430>::STD430_ALIGNMENT;
+            const STD430_SIZE: usize = <GlslArray<[$scalar; $rows], $columns> as Std430>::STD430_SIZE;
+            fn write_std430(&self, bytes: &mut Vec<u8>) {
+                GlslArray(*self).write_std430(bytes);
+            }
+            fn read_std430(bytes: &[u8]) -> Self {
+                GlslArray::read_std430(bytes).0
+            }
+            fn glsl_type() -> String {
+                format!("{}mat{}x{}", $prefix, $columns, $rows)
+            }
+        }
+    )*};
+}
+impl_layouts_matrix!(f32, ""; 2 x 2, 2 x 3, 2 x 4, 3 x 2, 3 x 3, 3 x 4, 4 x 2, 4 x 3, 4 x 4);
+impl_layouts_matrix!(f64, "d"; 2 x 2, 2 x 3, 2 x 4, 3 x 2, 3 x 3, 3 x 4, 4 x 2, 4 x 3, 4 x 4);
+
+/// The absence of data, for a [`crate::ShaderStorageBuffer`] without either a fixed part, or a
+/// runtime-sized array
+impl Std430 for () {
+    const STD430_ALIGNMENT: usize = 1;
+    const STD430_SIZE: usize = 0;
+    fn write_std430(&self, _bytes: &mut Vec<u8>) {}
+    fn read_std430(_bytes: &[u8]) -> Self {}
+    fn glsl_type() -> String {
+        String::new()
+    }
+}
+
+/// A wrapper to lay out `[T; N]` as a GLSL array, rather than a vector or matrix
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GlslArray<T, const N: usize>(pub [T; N]);
+impl<T: Std140, const N: usize> GlslArray<T, N> {
+    /// Every element is aligned as at least a vec4
+    const STD140_STRIDE: usize = T::STD140_SIZE.next_multiple_of(Self::STD140_ALIGNMENT);
+}
+impl<T: Std140, const N: usize> Std140 for GlslArray<T, N> {
+    const STD140_ALIGNMENT: usize = T::STD140_ALIGNMENT.next_multiple_of(16);
+    const STD140_SIZE: usize = Self::STD140_STRIDE * N;
+    fn write_std140(&self, bytes: &mut Vec<u8>) {
+        for element in &self.0 {
+            let start = bytes.len();
+            element.write_std140(bytes);
+            bytes.resize(start + Self::STD140_STRIDE, 0);
+        }
+    }
+    fn read_std140(bytes: &[u8]) -> Self {
+        Self(std::array::from_fn(|i| T::read_std140(&bytes[i * Self::STD140_STRIDE..])))
+    }
+}
+impl<T: Std430, const N: usize> GlslArray<T, N> {
+    /// Unlike `std140`, elements are not aligned as vec4s
+    const STD430_STRIDE: usize = T::STD430_SIZE.next_multiple_of(T::STD430_ALIGNMENT);
+}
+impl<T: Std430, const N: usize> Std430 for GlslArray<T, N> {
+    const STD430_ALIGNMENT: usize = T::STD430_ALIGNMENT;
+    const STD430_SIZE: usize = Self::STD430_STRIDE * N;
+    fn write_std430(&self, bytes: &mut Vec<u8>) {
+        for element in &self.0 {
+            let start = bytes.len();
+            element.write_std430(bytes);
+            bytes.resize(start + Self::STD430_STRIDE, 0);
+        }
+    }
+    fn read_std430(bytes: &[u8]) -> Self {
+        Self(std::array::from_fn(|i| T::read_std430(&bytes[i * Self::STD430_STRIDE..])))
+    }
+    fn glsl_type() -> String {
+        format!("{}[{N}]", T::glsl_type())
+    }
+    fn glsl_definitions(definitions: &mut Vec<String>) {
+        T::glsl_definitions(definitions);
+    }
+}
+
 use trait_derives::ToByteVec;
 #[derive(ToByteVec)]
 struct Tester;
@@ -1,8 +1,10 @@
 use crate::{prelude::*, MatInputAttributePointer, ThinInputAttribute};
 use crate::{ArrayBuffer,ElementBuffer,Input,OwlError};
+use crate::buffers::BufferName;
 use crate::oxidised_bindings as ox;
+use std::rc::Rc;
 
-pub use ox::{ FloatVertexFormat, IntegralVertexFormat, IntegralDataType, DataTypeSize3, DataTypeSize4, DataTypeSizeBgra, DataTypeUnsized };
+pub use ox::{ FloatVertexFormat, IntegralVertexFormat, DoubleVertexFormat, IntegralDataType, DataTypeSize3, DataTypeSize4, DataTypeSizeBgra, DataTypeUnsized };
 
 pub use crate::traits::Bytes;
 
@@ -28,62 +30,105 @@ impl InputArray {
             length: 0
         }
     }
+    /// Take the next `locations` locations, returning the first
+    ///
+    /// # Errors
+    /// This function will return an error if the max number of inputs would be exceeded
+    fn allocate(&mut self, locations: u8) -> Result<u8,OwlError> {
+        match self.length.checked_add(locations) {
+            Some(new_length) if new_length <= self.capacity => {
+                let index = self.length;
+                self.length = new_length;
+                Ok(index)
+            },
+            _ => Err(OwlError::custom("maximum inputs reached"))
+        }
+    }
+    /// Returns the location of the input
+    ///
     /// # Errors
     /// This function will return an error if the max number of inputs is reached
-    fn push<T: ToByteVec>(&mut self, attribute: ThinInputAttribute, pointer: AttributePointer<T>) -> Result<(),OwlError> {
-        if self.length >= self.capacity {
-            Err(OwlError::custom("maximum inputs reached"))
-        } else {
-            self.container.push(Input::new_thin(self.length, attribute, pointer ));
-            ox::enable_vertex_attrib_array(self.length)
-                .expect("vertex array bound, and next_index <= max_indices");
-            self.length += 1;
-            Ok(())
+    fn push<T: ToByteVec>(&mut self, attribute: ThinInputAttribute, pointer: AttributePointer<T>) -> Result<u8,OwlError> {
+        let index = self.allocate(attribute.locations())?;
+        self.container.push(Input::new_thin(index, attribute, pointer));
+        ox::enable_vertex_attrib_array(index)
+            .expect("vertex array bound, and next_index <= max_indices");
+        Ok(index)
+    }
+    /// As [`InputArray::push`], advancing the input once every `divisor` instances
+    ///
+    /// # Errors
+    /// This function will return an error if the max number of inputs is reached
+    fn push_instanced<T: ToByteVec>(&mut self, attribute: ThinInputAttribute, pointer: AttributePointer<T>,
+        divisor: u32) -> Result<(),OwlError> {
+        let index = self.push(attribute, pointer)?;
+        ox::vertex_attrib_divisor(index, divisor)
+            .expect("vertex array bound, and index checked");
+        Ok(())
+    }
+    /// As [`InputArray::push_mat`], advancing the input once every `divisor` instances
+    ///
+    /// # Errors
+    /// This function will return an error if the max number of inputs is reached
+    fn push_instanced_mat<T: ToByteVec>(&mut self, attribute_pointer: MatInputAttributePointer<T>,
+        divisor: u32) -> Result<(),OwlError> {
+        let column_locations = attribute_pointer.column_locations();
+        let columns = attribute_pointer.size();
+        let index = self.push_mat(attribute_pointer)?;
+        for column in 0..columns {
+            ox::vertex_attrib_divisor(index + column * column_locations, divisor)
+                .expect("vertex array bound, and index checked");
         }
+        Ok(())
     }
+    /// Returns the location of the first column, each column taking
+    /// [`MatInputAttributePointer::column_locations`]
+    ///
     /// # Errors
     /// This function will return an error if the max number of inputs is reached
-    fn push_mat<T: ToByteVec>(&mut self, attribute_pointer: MatInputAttributePointer<T>) -> Result<(),OwlError> {
-        let new_length = self.length + attribute_pointer.size();
-        if new_length > self.capacity {
-            Err(OwlError::custom("maximum inputs reached"))
-        } else {
-            self.container.push(Input::new_mat(self.length, attribute_pointer));
-            for index in self.length..new_length {
-                ox::enable_vertex_attrib_array(index)
-                    .expect("vertex array bound, and next_index <= max_indices");
-            }
-            self.length = new_length;
-            Ok(())
+    fn push_mat<T: ToByteVec>(&mut self, attribute_pointer: MatInputAttributePointer<T>) -> Result<u8,OwlError> {
+        let column_locations = attribute_pointer.column_locations();
+        let columns = attribute_pointer.size();
+        let index = self.allocate(attribute_pointer.locations())?;
+        self.container.push(Input::new_mat(index, attribute_pointer));
+        for column in 0..columns {
+            ox::enable_vertex_attrib_array(index + column * column_locations)
+                .expect("vertex array bound, and next_index <= max_indices");
         }
+        Ok(index)
     }
     /// # Errors
     /// This function will return an error if the max number of inputs is reached or the number of pointers > `u8::MAX`,
     // which would exceed the max anyway
     fn push_array<T: ToByteVec>(&mut self, length: u8, attribute: ThinInputAttribute, pointer: AttributePointer<T>) -> Result<(),OwlError> {
-        let new_length = self.length + length;
-        if new_length > self.capacity {
-            Err(OwlError::custom("maximum inputs reached"))
-        } else {
-            self.container.push(Input::new_thin_array(self.length, length, attribute, pointer));
-            for index in self.length..new_length {
-                ox::enable_vertex_attrib_array(index)
-                    .expect("vertex array bound, and next_index <= max_indices");
-            }
-            self.length = new_length;
-            Ok(())
+        let element_locations = attribute.locations();
+        let locations = length.checked_mul(element_locations)
+            .ok_or_else(|| OwlError::custom("maximum inputs reached"))?;
+        let index = self.allocate(locations)?;
+        self.container.push(Input::new_thin_array(index, length, attribute, pointer));
+        for i in 0..length {
+            ox::enable_vertex_attrib_array(index + i * element_locations)
+                .expect("vertex array bound, and next_index <= max_indices");
         }
+        Ok(())
     }
 }
 
-pub struct VertexArray<E: ToByteVec> {
+/// A set of vertex inputs, and optionally the indices of `E` they are drawn by
+///
+/// # Notes
+/// The buffers its inputs read from are kept alive until it is dropped: dropping an [`ArrayBuffer`]
+/// only deletes it once no vertex array reads from it.
+pub struct VertexArray<E: IndexElement = u32> {
     inner: ox::VertexArray,
     pub(crate) inputs: InputArray,
     pub(crate) elements: Option<ElementBuffer<E>>,
+    /// The names of the buffers read by `inputs`, each held once
+    buffers: Vec<Rc<BufferName>>,
 }
 #[allow(clippy::must_use_candidate)]
 #[allow(clippy::return_self_not_must_use)]
-impl<T: ToByteVec> VertexArray<T> {
+impl<T: IndexElement> VertexArray<T> {
     // INVARIANT: will not be deleted until it is dropped
     // fewer calls can fail, reducing error handling, but they now "expect"
     pub fn new() -> Self {
@@ -93,7 +138,8 @@ impl<T: ToByteVec> VertexArray<T> {
             inner: ox::gen_vertex_array(),
             inputs: InputArray::new(u8::try_from(ox::get_uint(ox::UIntParameter::MaxVertexAttribs))
                 .expect("practically always 16, should never exceed 255")),
-            elements: None
+            elements: None,
+            buffers: Vec::new(),
         }
     }
     pub fn with_indices(mut self, buffer: ElementBuffer<T>) -> Self {
@@ -102,11 +148,17 @@ impl<T: ToByteVec> VertexArray<T> {
         self.elements = Some(buffer);
         self
     }
+    /// The indices, e.g. to be updated or mapped; while they are borrowed, the vertex array
+    /// cannot be drawn with.
+    pub const fn indices_mut(&mut self) -> Option<&mut ElementBuffer<T>> {
+        self.elements.as_mut()
+    }
     /// # Errors
     ///
     /// This function will return an error if the maximum number of inputs is exceeded.
     pub fn with_input_array<U: ToByteVec>(mut self, length: u8, attribute: ThinInputAttribute, pointer: AttributePointer<U>) -> Result<Self,OwlError> {
         self.bind();
+        self.keep_alive(pointer.buffer);
         self.inputs.push_array(length, attribute, pointer)?;
         Ok(self)
     }
@@ -115,19 +167,103 @@ impl<T: ToByteVec> VertexArray<T> {
     /// This function will return an error if the maximum number of inputs is exceeded.
     pub fn with_input<U: ToByteVec>(mut self, attribute: ThinInputAttribute, pointer: AttributePointer<U>) -> Result<Self,OwlError> {
         self.bind();
+        self.keep_alive(pointer.buffer);
         self.inputs.push(attribute, pointer)?;
         Ok(self)
     }
+    /// Take an input from each field of the vertices in `buffer`, as described by [`Vertex`]
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the stride of the vertices depends on their values,
+    /// or the maximum number of inputs is exceeded.
+    pub fn with_vertex_buffer<V: Vertex>(mut self, buffer: &ArrayBuffer<V>) -> Result<Self,OwlError> {
+        self.push_vertex_buffer(buffer, 0).with_context("taking inputs from a vertex buffer")?;
+        Ok(self)
+    }
+    /// As [`VertexArray::with_input`], but the input advances once every `divisor` instances,
+    /// rather than every vertex, e.g. for the position of each of many copies of a mesh
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the maximum number of inputs is exceeded.
+    pub fn with_instanced_input<U: ToByteVec>(mut self, attribute: ThinInputAttribute, pointer: AttributePointer<U>,
+        divisor: u32) -> Result<Self,OwlError> {
+        self.bind();
+        self.keep_alive(pointer.buffer);
+        self.inputs.push_instanced(attribute, pointer, divisor)?;
+        Ok(self)
+    }
+    /// As [`VertexArray::with_vertex_buffer`], but every input advances once every `divisor`
+    /// instances, rather than every vertex
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the stride of the instances depends on their values,
+    /// or the maximum number of inputs is exceeded.
+    pub fn with_instance_buffer<V: Vertex>(mut self, buffer: &ArrayBuffer<V>, divisor: u32) -> Result<Self,OwlError> {
+        self.push_vertex_buffer(buffer, divisor).with_context("taking inputs from an instance buffer")?;
+        Ok(self)
+    }
+    /// Take an input from each field of the vertices in `buffer`, advancing once every `divisor` instances,
+    /// or every vertex if it is 0
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the stride of the vertices depends on their values,
+    /// or the maximum number of inputs is exceeded.
+    fn push_vertex_buffer<V: Vertex>(&mut self, buffer: &ArrayBuffer<V>, divisor: u32) -> Result<(),OwlError> {
+        let stride = V::STRIDE.ok_or_else(|| OwlError::custom("vertices must all be the same size"))?;
+        self.bind();
+        self.keep_alive(buffer);
+        for (attribute, offset) in V::inputs() {
+            self.inputs.push_instanced(attribute, AttributePointer { buffer, stride, offset }, divisor)?;
+        }
+        Ok(())
+    }
+    /// Input a matrix at as many consecutive locations as it has columns (twice as many for a `dmat3`
+    /// or `dmat4`), reading each column from the pointer's offset plus the size of those before it
+    ///
     /// # Errors
     ///
     /// This function will return an error if the maximum number of inputs is exceeded.
-    pub fn with_input_mat<U: ToByteVec>(mut self, attribute: MatInputAttributePointer<T>) -> Result<Self, OwlError> {
+    pub fn with_input_mat<U: ToByteVec>(mut self, attribute: MatInputAttributePointer<U>) -> Result<Self, OwlError> {
         self.bind();
+        self.keep_alive(attribute.pointer().buffer);
         self.inputs.push_mat(attribute)?;
         Ok(self)
     }
+    /// As [`VertexArray::with_input_mat`], but the input advances once every `divisor` instances,
+    /// e.g. for the model matrix of each of many copies of a mesh
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the maximum number of inputs is exceeded.
+    pub fn with_instanced_input_mat<U: ToByteVec>(mut self, attribute: MatInputAttributePointer<U>,
+        divisor: u32) -> Result<Self, OwlError> {
+        self.bind();
+        self.keep_alive(attribute.pointer().buffer);
+        self.inputs.push_instanced_mat(attribute, divisor)?;
+        Ok(self)
+    }
+    /// Hold the name of `buffer`, so that it is not deleted while an input reads from it
+    fn keep_alive<U: ToByteVec>(&mut self, buffer: &ArrayBuffer<U>) {
+        let name = buffer.shared_name();
+        if !self.buffers.iter().any(|held| Rc::ptr_eq(held, &name)) {
+            self.buffers.push(name);
+        }
+    }
+    /// # Errors
+    /// This function will return an error if any buffer read by the inputs is being mapped.
+    pub(crate) fn check_unmapped(&self) -> Result<(),OwlError> {
+        if self.buffers.iter().any(|buffer| buffer.is_mapped()) {
+            Err(OwlError::custom("a vertex buffer is being mapped"))
+        } else {
+            Ok(())
+        }
+    }
     pub(crate) fn bind(&self) {
-        ox::bind_vertex_array(Some(self.inner))
+        ox::bind_vertex_array(Some(&self.inner))
             .expect("vertex array should not be deleted yet");
     }
     fn unbind() {
@@ -136,14 +272,14 @@ impl<T: ToByteVec> VertexArray<T> {
     }
 }
 
-impl<T: ToByteVec> Default for VertexArray<T> {
+impl<T: IndexElement> Default for VertexArray<T> {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl<T: ToByteVec> Drop for VertexArray<T> {
+impl<T: IndexElement> Drop for VertexArray<T> {
     fn drop(&mut self) {
-        ox::delete_vertex_array(self.inner);
+        ox::delete_vertex_array(&self.inner);
     }
 }
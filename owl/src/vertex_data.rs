@@ -0,0 +1,418 @@
+use crate::prelude::*;
+use crate::{ThinInputAttribute, ThinFloatAttributeType, FloatVertexFormat, DataTypeUnsized, DataTypeSize3,
+    DataTypeSize4};
+
+/// A half precision float, input as a `float`, or as a `vecN` in an array
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct F16(pub u16);
+impl F16 {
+    /// The nearest half to `value`, rounding ties to even, and beyond the largest half to infinity
+    #[must_use]
+    pub const fn from_f32(value: f32) -> Self {
+        let bits = value.to_bits();
+        // CAST: only the sign bit remains
+        #[allow(clippy::cast_possible_truncation)]
+        let sign = ((bits >> 16) & 0x8000) as u16;
+        // CAST: only 8 bits remain
+        #[allow(clippy::cast_possible_wrap)]
+        let exponent = ((bits >> 23) & 0xff) as i32;
+        let mantissa = bits & 0x007f_ffff;
+        if exponent == 0xff {
+            // infinity stays infinity, and NaN stays NaN
+            return Self(sign | 0x7c00 | if mantissa == 0 { 0 } else { 0x0200 });
+        }
+        let exponent = exponent - 127 + 15;
+        if exponent >= 0x1f {
+            return Self(sign | 0x7c00);
+        }
+        if exponent <= 0 {
+            // too small for a normal half, so a subnormal, or zero
+            if exponent < -10 {
+                return Self(sign);
+            }
+            let mantissa = mantissa | 0x0080_0000;
+            // CAST: exponent in -10..=0
+            #[allow(clippy::cast_sign_loss)]
+            let shift = (14 - exponent) as u32;
+            let rounded = (mantissa + (1 << (shift - 1)) - 1 + ((mantissa >> shift) & 1)) >> shift;
+            // CAST: mantissa < 2^24, shifted by at least 14
+            #[allow(clippy::cast_possible_truncation)]
+            return Self(sign | rounded as u16);
+        }
+        // CAST: exponent in 1..31
+        #[allow(clippy::cast_sign_loss)]
+        let half = ((exponent as u32) << 10) | (mantissa >> 13);
+        let remainder = mantissa & 0x1fff;
+        // a carry into the exponent is still correct, even to infinity
+        let rounded = if remainder > 0x1000 || (remainder == 0x1000 && half & 1 == 1) { half + 1 } else { half };
+        // CAST: at most 0x7c00
+        #[allow(clippy::cast_possible_truncation)]
+        Self(sign | rounded as u16)
+    }
+    /// The exact value of the half
+    #[must_use]
+    pub fn to_f32(self) -> f32 {
+        let sign = u32::from(self.0 & 0x8000) << 16;
+        let exponent = u32::from(self.0 >> 10) & 0x1f;
+        let mantissa = u32::from(self.0 & 0x03ff);
+        match exponent {
+            0 => {
+                // subnormal: mantissa * 2^-24
+                let magnitude = f32::from(self.0 & 0x03ff) * f32::from_bits(0x3380_0000);
+                if sign == 0 { magnitude } else { -magnitude }
+            },
+            0x1f => f32::from_bits(sign | 0x7f80_0000 | (mantissa << 13)),
+            _ => f32::from_bits(sign | ((exponent + 127 - 15) << 23) | (mantissa << 13)),
+        }
+    }
+}
+impl From<f32> for F16 {
+    fn from(value: f32) -> Self {
+        Self::from_f32(value)
+    }
+}
+impl From<F16> for f32 {
+    fn from(value: F16) -> Self {
+        value.to_f32()
+    }
+}
+
+/// A 16.16 fixed point number, input as a `float`, or as a `vecN` in an array
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Fixed(pub i32);
+impl Fixed {
+    /// The nearest fixed point number to `value`, saturating at the largest and smallest
+    #[must_use]
+    pub fn from_f32(value: f32) -> Self {
+        // CAST: float to int casts saturate
+        #[allow(clippy::cast_possible_truncation)]
+        Self((value * 65536.0).round() as i32)
+    }
+    /// The nearest float to the fixed point number
+    #[must_use]
+    pub fn to_f32(self) -> f32 {
+        // CAST: precision lost beyond 24 bits, as with any float
+        #[allow(clippy::cast_precision_loss)]
+        let value = self.0 as f32;
+        value / 65536.0
+    }
+}
+
+/// Three 10-bit and one 2-bit unsigned integers, packed into 32 bits with `x` in the lowest,
+/// input as a `vec4`, e.g. as quantised colours with `#[owl(normalise)]`
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Packed1010102(pub u32);
+impl Packed1010102 {
+    /// Pack the lowest 10 bits of `x`, `y` and `z`, and the lowest 2 of `w`
+    #[must_use]
+    pub fn new(x: u16, y: u16, z: u16, w: u8) -> Self {
+        Self(u32::from(x & 0x3ff) | u32::from(y & 0x3ff) << 10 | u32::from(z & 0x3ff) << 20
+            | u32::from(w & 0x3) << 30)
+    }
+    /// Pack values in [0,1], as they are read when normalised
+    #[must_use]
+    pub fn from_normalised(x: f32, y: f32, z: f32, w: f32) -> Self {
+        // CAST: clamped to [0,1], so in range
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let quantise = |value: f32, max: f32| (value.clamp(0.0, 1.0) * max).round() as u16;
+        // CAST: at most 3
+        #[allow(clippy::cast_possible_truncation)]
+        Self::new(quantise(x, 1023.0), quantise(y, 1023.0), quantise(z, 1023.0), quantise(w, 3.0) as u8)
+    }
+}
+
+/// Three 10-bit and one 2-bit signed integers, packed into 32 bits with `x` in the lowest,
+/// input as a `vec4`, e.g. as quantised normals with `#[owl(normalise)]`
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct PackedSigned1010102(pub u32);
+impl PackedSigned1010102 {
+    /// Pack the lowest 10 bits of `x`, `y` and `z`, and the lowest 2 of `w`, in two's complement
+    #[must_use]
+    pub fn new(x: i16, y: i16, z: i16, w: i8) -> Self {
+        // CAST: reinterpreted as two's complement, then masked
+        #[allow(clippy::cast_sign_loss)]
+        Self(u32::from(x as u16 & 0x3ff) | u32::from(y as u16 & 0x3ff) << 10
+            | u32::from(z as u16 & 0x3ff) << 20 | u32::from(w as u8 & 0x3) << 30)
+    }
+    /// Pack values in [-1,1], as they are read when normalised
+    #[must_use]
+    pub fn from_normalised(x: f32, y: f32, z: f32, w: f32) -> Self {
+        // CAST: clamped to [-1,1], so in range
+        #[allow(clippy::cast_possible_truncation)]
+        let quantise = |value: f32, max: f32| (value.clamp(-1.0, 1.0) * max).round() as i16;
+        // CAST: in -1..=1
+        #[allow(clippy::cast_possible_truncation)]
+        Self::new(quantise(x, 511.0), quantise(y, 511.0), quantise(z, 511.0), quantise(w, 1.0) as i8)
+    }
+}
+
+/// Two 11-bit and one 10-bit unsigned floats, packed into 32 bits with red in the lowest,
+/// input as a `vec3`, e.g. as HDR colours
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct PackedR11G11B10F(pub u32);
+impl PackedR11G11B10F {
+    /// Pack each channel, truncating its mantissa; negative values and NaN become 0
+    #[must_use]
+    pub fn new(red: f32, green: f32, blue: f32) -> Self {
+        // the unsigned floats share the exponent of a half, with a truncated mantissa
+        let half = |value: f32| if value > 0.0 { u32::from(F16::from_f32(value).0) } else { 0 };
+        Self(half(red) >> 4 | (half(green) >> 4) << 11 | (half(blue) >> 5) << 22)
+    }
+}
+
+/// An integer, input as a float in [0,1] if it is unsigned, or [-1,1] if it is signed,
+/// or as a `vecN` in an array
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Normalised<T>(pub T);
+macro_rules! impl_normalised {
+    ($($integer:ty => $min:literal),*) => {$(
+        impl Normalised<$integer> {
+            /// The nearest integer to `value`, as it is read, clamping it to the range
+            #[must_use]
+            pub fn from_f32(value: f32) -> Self {
+                // CAST: clamped to the range, and float to int casts saturate
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                Self((f64::from(value.clamp($min, 1.0)) * f64::from(<$integer>::MAX)).round() as $integer)
+            }
+            /// The value, as it is read
+            #[must_use]
+            pub fn to_f32(self) -> f32 {
+                // CAST: precision lost beyond 24 bits, as with any float
+                #[allow(clippy::cast_possible_truncation)]
+                let value = (f64::from(self.0) / f64::from(<$integer>::MAX)) as f32;
+                value.max($min)
+            }
+        }
+        impl ToByteVec for Normalised<$integer> {
+            const STRIDE: Option<Bytes> = <$integer as ToByteVec>::STRIDE;
+            fn to_byte_vec(self) -> Vec<u8> {
+                self.0.to_byte_vec()
+            }
+            fn stride(&self) -> Bytes {
+                self.0.stride()
+            }
+        }
+        impl FromByteVec for Normalised<$integer> {
+            const BYTE_SIZE: usize = <$integer as FromByteVec>::BYTE_SIZE;
+            fn from_byte_slice(bytes: &[u8]) -> Self {
+                Self(<$integer>::from_byte_slice(bytes))
+            }
+        }
+        // SAFETY: transparent over an integer
+        unsafe impl Pod for Normalised<$integer> {}
+        /// Always normalised, whether or not the field is marked
+        impl VertexField for Normalised<$integer> {
+            fn float_input(name: String, _normalise: bool) -> ThinInputAttribute {
+                <$integer as VertexField>::float_input(name, true)
+            }
+        }
+        impl_normalised!(@array $integer, 2);
+        impl_normalised!(@array $integer, 3);
+        impl_normalised!(@array $integer, 4);
+    )*};
+    (@array $integer:ty, $length:literal) => {
+        /// Always normalised, whether or not the field is marked
+        impl VertexField for [Normalised<$integer>; $length] {
+            fn float_input(name: String, _normalise: bool) -> ThinInputAttribute {
+                <[$integer; $length] as VertexField>::float_input(name, true)
+            }
+        }
+    };
+}
+impl_normalised!(u8 => 0.0, i8 => -1.0, u16 => 0.0, i16 => -1.0, u32 => 0.0, i32 => -1.0);
+
+/// The byte traits of a type transparent over `$integer`
+macro_rules! impl_packed_bytes {
+    ($($packed:ty => $integer:ty),*) => {$(
+        impl ToByteVec for $packed {
+            const STRIDE: Option<Bytes> = <$integer as ToByteVec>::STRIDE;
+            fn to_byte_vec(self) -> Vec<u8> {
+                self.0.to_byte_vec()
+            }
+            fn stride(&self) -> Bytes {
+                self.0.stride()
+            }
+        }
+        impl FromByteVec for $packed {
+            const BYTE_SIZE: usize = <$integer as FromByteVec>::BYTE_SIZE;
+            fn from_byte_slice(bytes: &[u8]) -> Self {
+                Self(<$integer>::from_byte_slice(bytes))
+            }
+        }
+        // SAFETY: transparent over an integer
+        unsafe impl Pod for $packed {}
+    )*};
+}
+impl_packed_bytes!(F16 => u16, Fixed => i32, Packed1010102 => u32, PackedSigned1010102 => u32,
+    PackedR11G11B10F => u32);
+
+/// A scalar whose vertex data type has the same name for every size
+macro_rules! impl_scalar_vertex_field {
+    ($($scalar:ty => $data_type:ident),*) => {$(
+        impl VertexField for $scalar {
+            fn float_input(name: String, normalise: bool) -> ThinInputAttribute {
+                ThinInputAttribute::Float { name, glsl_type: ThinFloatAttributeType::Float,
+                    data_format: FloatVertexFormat::Size1 { normalise, data_type: DataTypeUnsized::$data_type } }
+            }
+        }
+        impl VertexField for [$scalar; 2] {
+            fn float_input(name: String, normalise: bool) -> ThinInputAttribute {
+                ThinInputAttribute::Float { name, glsl_type: ThinFloatAttributeType::Vec2,
+                    data_format: FloatVertexFormat::Size2 { normalise, data_type: DataTypeUnsized::$data_type } }
+            }
+        }
+        impl VertexField for [$scalar; 3] {
+            fn float_input(name: String, normalise: bool) -> ThinInputAttribute {
+                ThinInputAttribute::Float { name, glsl_type: ThinFloatAttributeType::Vec3,
+                    data_format: FloatVertexFormat::Size3 { normalise, data_type: DataTypeSize3::$data_type } }
+            }
+        }
+        impl VertexField for [$scalar; 4] {
+            fn float_input(name: String, normalise: bool) -> ThinInputAttribute {
+                ThinInputAttribute::Float { name, glsl_type: ThinFloatAttributeType::Vec4,
+                    data_format: FloatVertexFormat::Size4 { normalise, data_type: DataTypeSize4::$data_type } }
+            }
+        }
+    )*};
+}
+impl_scalar_vertex_field!(F16 => HalfFloat, Fixed => Fixed);
+
+impl VertexField for Packed1010102 {
+    fn float_input(name: String, normalise: bool) -> ThinInputAttribute {
+        ThinInputAttribute::Float { name, glsl_type: ThinFloatAttributeType::Vec4,
+            data_format: FloatVertexFormat::Size4 { normalise, data_type: DataTypeSize4::UnsignedInt2_10_10_10Rev } }
+    }
+}
+impl VertexField for PackedSigned1010102 {
+    fn float_input(name: String, normalise: bool) -> ThinInputAttribute {
+        ThinInputAttribute::Float { name, glsl_type: ThinFloatAttributeType::Vec4,
+            data_format: FloatVertexFormat::Size4 { normalise, data_type: DataTypeSize4::Int2_10_10_10Rev } }
+    }
+}
+/// Floats are never normalised, whether or not the field is marked
+impl VertexField for PackedR11G11B10F {
+    fn float_input(name: String, _normalise: bool) -> ThinInputAttribute {
+        ThinInputAttribute::Float { name, glsl_type: ThinFloatAttributeType::Vec3,
+            data_format: FloatVertexFormat::Size3 { normalise: false, data_type: DataTypeSize3::UnsignedInt10f11f11fRev } }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Every finite, positive half, in increasing order
+    fn positive_halves() -> impl Iterator<Item = F16> {
+        (0..0x7c00).map(F16)
+    }
+
+    #[test]
+    fn f16_round_trips() {
+        for half in (0..=u16::MAX).map(F16) {
+            let value = half.to_f32();
+            if value.is_nan() {
+                assert!(F16::from_f32(value).to_f32().is_nan(), "{half:?}");
+            } else {
+                assert_eq!(F16::from_f32(value), half, "{value}");
+            }
+        }
+    }
+
+    #[test]
+    fn f16_rounds_to_nearest_even() {
+        for (below, above) in positive_halves().zip(positive_halves().skip(1)) {
+            // halfway between neighbouring halves needs at most 12 bits of mantissa, so is exact
+            let midpoint = f32::midpoint(below.to_f32(), above.to_f32());
+            let even = if below.0 & 1 == 0 { below } else { above };
+            assert_eq!(F16::from_f32(midpoint), even, "{midpoint}");
+            assert_eq!(F16::from_f32(f32::from_bits(midpoint.to_bits() - 1)), below, "{midpoint}");
+            assert_eq!(F16::from_f32(f32::from_bits(midpoint.to_bits() + 1)), above, "{midpoint}");
+            assert_eq!(F16::from_f32(-midpoint), F16(even.0 | 0x8000), "{midpoint}");
+        }
+    }
+
+    #[test]
+    fn f16_ties_to_even() {
+        // 1 + 2^-11 lies halfway between 1 and 1 + 2^-10
+        assert_eq!(F16::from_f32(1.0 + 2.0_f32.powi(-11)), F16(0x3c00));
+        assert_eq!(F16::from_f32(3.0_f32.mul_add(2.0_f32.powi(-11), 1.0)), F16(0x3c02));
+    }
+
+    #[test]
+    fn f16_subnormals() {
+        assert_eq!(F16::from_f32(2.0_f32.powi(-24)), F16(0x0001));
+        assert_eq!(F16(0x0001).to_f32().to_bits(), 2.0_f32.powi(-24).to_bits());
+        assert_eq!(F16(0x03ff).to_f32().to_bits(), (1023.0 * 2.0_f32.powi(-24)).to_bits());
+        // halfway between 0 and the smallest subnormal rounds to 0, the even one
+        assert_eq!(F16::from_f32(2.0_f32.powi(-25)), F16(0x0000));
+        assert_eq!(F16::from_f32(3.0 * 2.0_f32.powi(-25)), F16(0x0002));
+        assert_eq!(F16::from_f32(-2.0_f32.powi(-24)), F16(0x8001));
+        // halfway between the largest subnormal and the smallest normal carries into the exponent
+        assert_eq!(F16::from_f32(1023.5 * 2.0_f32.powi(-24)), F16(0x0400));
+        assert_eq!(F16::from_f32(f32::MIN_POSITIVE), F16(0x0000));
+        assert_eq!(F16::from_f32(-0.0), F16(0x8000));
+    }
+
+    #[test]
+    fn f16_overflows_to_infinity() {
+        assert_eq!(F16::from_f32(65504.0), F16(0x7bff));
+        assert_eq!(F16::from_f32(65519.0), F16(0x7bff));
+        // halfway between the largest half and 2^16 rounds to even, which is infinity
+        assert_eq!(F16::from_f32(65520.0), F16(0x7c00));
+        assert_eq!(F16::from_f32(1e10), F16(0x7c00));
+        assert_eq!(F16::from_f32(-1e10), F16(0xfc00));
+        assert_eq!(F16::from_f32(f32::INFINITY), F16(0x7c00));
+        assert_eq!(F16::from_f32(f32::NEG_INFINITY), F16(0xfc00));
+        assert_eq!(F16(0x7c00).to_f32().to_bits(), f32::INFINITY.to_bits());
+    }
+
+    #[test]
+    fn f16_nan() {
+        assert!(F16::from_f32(f32::NAN).to_f32().is_nan());
+        // a NaN with only low mantissa bits must not become infinity
+        assert!(F16::from_f32(f32::from_bits(0x7f80_0001)).to_f32().is_nan());
+        assert!(F16(0x7e00).to_f32().is_nan());
+    }
+
+    #[test]
+    fn packed_1010102_channels() {
+        assert_eq!(Packed1010102::new(0x3ff, 0, 0, 0).0, 0x0000_03ff);
+        assert_eq!(Packed1010102::new(0, 0x3ff, 0, 0).0, 0x000f_fc00);
+        assert_eq!(Packed1010102::new(0, 0, 0x3ff, 0).0, 0x3ff0_0000);
+        assert_eq!(Packed1010102::new(0, 0, 0, 0x3).0, 0xc000_0000);
+        // bits beyond each channel are dropped
+        assert_eq!(Packed1010102::new(0x7ff, 0, 0, 0x7).0, 0xc000_03ff);
+        // 0.5 * 1023 rounds up to 0x200
+        assert_eq!(Packed1010102::from_normalised(1.0, 0.0, 0.5, 1.0).0, 0xe000_03ff);
+    }
+
+    #[test]
+    fn packed_signed_1010102_channels() {
+        assert_eq!(PackedSigned1010102::new(-1, 0, 0, 0).0, 0x0000_03ff);
+        assert_eq!(PackedSigned1010102::new(0, -512, 0, 0).0, 0x0008_0000);
+        assert_eq!(PackedSigned1010102::new(0, 0, 511, 0).0, 0x1ff0_0000);
+        assert_eq!(PackedSigned1010102::new(0, 0, 0, -1).0, 0xc000_0000);
+        assert_eq!(PackedSigned1010102::new(0, 0, 0, 1).0, 0x4000_0000);
+        assert_eq!(PackedSigned1010102::from_normalised(1.0, -1.0, 0.0, -1.0).0,
+            PackedSigned1010102::new(511, -511, 0, -1).0);
+    }
+
+    #[test]
+    fn packed_r11g11b10f_channels() {
+        // 1.0 has a biased exponent of 15 and no mantissa, in every channel
+        assert_eq!(PackedR11G11B10F::new(1.0, 0.0, 0.0).0, 15 << 6);
+        assert_eq!(PackedR11G11B10F::new(0.0, 1.0, 0.0).0, 15 << 6 << 11);
+        assert_eq!(PackedR11G11B10F::new(0.0, 0.0, 1.0).0, 15 << 5 << 22);
+        // 1.5 keeps the top mantissa bit, in the 6 of red and green and the 5 of blue
+        assert_eq!(PackedR11G11B10F::new(1.5, 1.5, 1.5).0,
+            (15 << 6 | 1 << 5) | (15 << 6 | 1 << 5) << 11 | (15 << 5 | 1 << 4) << 22);
+        assert_eq!(PackedR11G11B10F::new(f32::INFINITY, 0.0, 0.0).0, 0x1f << 6);
+        assert_eq!(PackedR11G11B10F::new(-1.0, f32::NAN, -0.0).0, 0);
+    }
+}
@@ -1,36 +1,563 @@
 use proc_macro::TokenStream;
 use quote::quote;
 
-#[proc_macro_derive(ToByteVec)]
+/// With `#[to_byte_vec(repr_c)]`, on a `#[repr(C)]` struct, fields are written at their offsets
+/// in memory, with zeroed padding between them and at the end, so that `stride` and `field_offset`
+/// match the layout of the struct. Otherwise, fields are written back to back.
+///
+/// Fieldless enums are written as their discriminant, and so must be `#[repr(u8)]`, `#[repr(u16)]`
+/// or `#[repr(u32)]`. Type parameters are bound by `ToByteVec`.
+#[proc_macro_derive(ToByteVec, attributes(to_byte_vec))]
 pub fn to_byte_vec_derive(input: TokenStream) -> TokenStream {
     let syn::DeriveInput {
         ident,
         data,
         generics,
+        attrs,
         ..
     } = syn::parse_macro_input!(input as syn::DeriveInput);
-    if let syn::Data::Struct(data_struct) = data {
-        let where_clause = &generics.where_clause;
-        let to_byte_stream = parse_fields_to_bytes(&data_struct);
-        let stride_stream = parse_fields_to_stride(&data_struct);
-        let offset_stream = parse_fields_to_offsets(&data_struct);
-        quote! {
-            impl #generics ToByteVec for #ident #generics #where_clause {
-                fn to_byte_vec(self) -> Vec<u8> {
-                    #to_byte_stream
+    let generics = bound_type_parameters(generics, &syn::parse_quote!(ToByteVec));
+    match data {
+        syn::Data::Struct(data_struct) => {
+            match repr_c_mode(&attrs, &ident) {
+                Ok(true) => return repr_c_to_byte_vec(&ident, &generics, &data_struct.fields).into(),
+                Ok(false) => {},
+                Err(e) => return e.to_compile_error().into(),
+            }
+            let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+            let to_byte_stream = parse_fields_to_bytes(&data_struct);
+            let stride_stream = parse_fields_to_stride(&data_struct);
+            let offset_stream = parse_fields_to_offsets(&data_struct);
+            let layout_stream = packed_layout(&data_struct.fields);
+            quote! {
+                impl #impl_generics ToByteVec for #ident #type_generics #where_clause {
+                    #layout_stream
+                    fn to_byte_vec(self) -> Vec<u8> {
+                        #to_byte_stream
+                    }
+                    fn stride(&self) -> Bytes {
+                        #stride_stream
+                    }
+                    fn field_offset(&self, field_index: usize) -> Option<Bytes> {
+                        #offset_stream
+                    }
                 }
-                fn stride(&self) -> Bytes {
-                    #stride_stream
+            }
+            .into()
+        },
+        syn::Data::Enum(data_enum) => enum_to_byte_vec(&ident, &generics, &attrs, &data_enum)
+            .unwrap_or_else(syn::Error::into_compile_error).into(),
+        syn::Data::Union(data_union) => syn::Error::new(data_union.union_token.span,
+            "ToByteVec cannot be derived for unions, as which field to write is unknown")
+            .to_compile_error().into(),
+    }
+}
+
+/// Add `bound` to every type parameter of `generics`
+fn bound_type_parameters(mut generics: syn::Generics, bound: &syn::TypeParamBound) -> syn::Generics {
+    for parameter in generics.type_params_mut() {
+        parameter.bounds.push(bound.clone());
+    }
+    generics
+}
+
+/// A fieldless enum, written as its discriminant
+///
+/// # Errors
+/// This function will return an error if any variant has fields, or the enum is not `#[repr(u8)]`,
+/// `#[repr(u16)]` or `#[repr(u32)]`.
+fn enum_to_byte_vec(ident: &syn::Ident, generics: &syn::Generics, attrs: &[syn::Attribute],
+    data: &syn::DataEnum) -> Result<proc_macro2::TokenStream, syn::Error> {
+    if let Some(attr) = attrs.iter().find(|a| a.path().is_ident("to_byte_vec")) {
+        return Err(syn::Error::new_spanned(attr, "to_byte_vec options only apply to structs"));
+    }
+    if let Some(variant) = data.variants.iter().find(|v| !matches!(v.fields, syn::Fields::Unit)) {
+        return Err(syn::Error::new_spanned(&variant.fields,
+            "ToByteVec can only be derived for enums without fields"));
+    }
+    let mut repr = None;
+    for attr in attrs.iter().filter(|a| a.path().is_ident("repr")) {
+        attr.parse_nested_meta(|meta| {
+            if ["u8", "u16", "u32"].iter().any(|integer| meta.path.is_ident(integer)) {
+                repr = meta.path.get_ident().cloned();
+            }
+            Ok(())
+        })?;
+    }
+    let Some(repr) = repr else {
+        return Err(syn::Error::new(ident.span(),
+            "ToByteVec enums must be `#[repr(u8)]`, `#[repr(u16)]` or `#[repr(u32)]`"));
+    };
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ToByteVec for #ident #type_generics #where_clause {
+            const STRIDE: Option<Bytes> = <#repr as ToByteVec>::STRIDE;
+            fn to_byte_vec(self) -> Vec<u8> {
+                (self as #repr).to_byte_vec()
+            }
+            fn stride(&self) -> Bytes {
+                Bytes(::core::mem::size_of::<#repr>())
+            }
+        }
+    })
+}
+
+/// The layout constants of a struct whose fields are written back to back, each offset being known
+/// if the strides of the fields before it are
+fn packed_layout(fields: &syn::Fields) -> proc_macro2::TokenStream {
+    let field_types: Vec<_> = fields.iter().map(|f| &f.ty).collect();
+    let field_names = field_names(fields);
+    let count = fields.len();
+    let indices = 0..count;
+    quote! {
+        const STRIDE: Option<Bytes> = 'stride: {
+            let mut end = 0;
+            #(
+                let Some(Bytes(stride)) = <#field_types as ToByteVec>::STRIDE else {
+                    break 'stride None;
+                };
+                end += stride;
+            )*
+            Some(Bytes(end))
+        };
+        const FIELD_NAMES: &'static [&'static str] = &[#(#field_names),*];
+        const FIELD_OFFSETS: &'static [Option<Bytes>] = &{
+            let mut offsets = [None; #count];
+            let mut end = Some(0);
+            #(
+                offsets[#indices] = match end {
+                    Some(end) => Some(Bytes(end)),
+                    None => None,
+                };
+                end = match (end, <#field_types as ToByteVec>::STRIDE) {
+                    (Some(end), Some(Bytes(stride))) => Some(end + stride),
+                    _ => None,
+                };
+            )*
+            offsets
+        };
+    }
+}
+
+/// The names of the fields, as `offset_of!` takes them
+fn field_names(fields: &syn::Fields) -> Vec<String> {
+    fields.iter().enumerate().map(|(i, f)| match &f.ident {
+        Some(ident) => ident.to_string(),
+        None => i.to_string()
+    }).collect()
+}
+
+/// Whether the struct asks for `repr(C)` layout with `#[to_byte_vec(repr_c)]`,
+/// which is an error unless it is also `#[repr(C)]`
+fn repr_c_mode(attrs: &[syn::Attribute], ident: &syn::Ident) -> Result<bool, syn::Error> {
+    let mut requested = false;
+    for attr in attrs.iter().filter(|a| a.path().is_ident("to_byte_vec")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("repr_c") {
+                requested = true;
+                Ok(())
+            } else {
+                Err(meta.error("unknown to_byte_vec option, expected `repr_c`"))
+            }
+        })?;
+    }
+    if !requested {
+        return Ok(false);
+    }
+    let mut repr_c = false;
+    for attr in attrs.iter().filter(|a| a.path().is_ident("repr")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("C") {
+                repr_c = true;
+            }
+            Ok(())
+        })?;
+    }
+    if repr_c {
+        Ok(true)
+    } else {
+        Err(syn::Error::new(ident.span(), "`#[to_byte_vec(repr_c)]` requires the struct to be `#[repr(C)]`"))
+    }
+}
+
+/// The offsets of each field under the `repr(C)` layout rules, as a hidden associated constant which
+/// fails to evaluate if they differ from `offset_of!`
+fn repr_c_offsets(ident: &syn::Ident, generics: &syn::Generics, fields: &syn::Fields) -> proc_macro2::TokenStream {
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+    let field_types: Vec<_> = fields.iter().map(|f| &f.ty).collect();
+    let field_accessors = field_accessors(fields);
+    let indices = 0..fields.len();
+    let count = fields.len();
+    // a generic struct's offsets are checked when to_byte_vec is instantiated, which uses them
+    let check = generics.params.is_empty().then(|| quote! {
+        const _: () = {
+            let _ = #ident::REPR_C_OFFSETS;
+        };
+    });
+    quote! {
+        impl #impl_generics #ident #type_generics #where_clause {
+            #[doc(hidden)]
+            const REPR_C_OFFSETS: [usize; #count] = {
+                let mut offsets = [0; #count];
+                let mut end: usize = 0;
+                #(
+                    offsets[#indices] = end.next_multiple_of(::core::mem::align_of::<#field_types>());
+                    end = offsets[#indices] + ::core::mem::size_of::<#field_types>();
+                    assert!(offsets[#indices] == ::core::mem::offset_of!(Self, #field_accessors),
+                        "field offset differs from the repr(C) layout");
+                )*
+                offsets
+            };
+        }
+        #check
+    }
+}
+
+fn repr_c_to_byte_vec(ident: &syn::Ident, generics: &syn::Generics, fields: &syn::Fields) -> proc_macro2::TokenStream {
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+    let offsets = repr_c_offsets(ident, generics, fields);
+    let field_names = field_names(fields);
+    let offset_indices = 0..fields.len();
+    let field_types: Vec<_> = fields.iter().map(|f| &f.ty).collect();
+    let field_accessors = field_accessors(fields);
+    let indices = 0..fields.len();
+    quote! {
+        #offsets
+        impl #impl_generics ToByteVec for #ident #type_generics #where_clause {
+            const STRIDE: Option<Bytes> = Some(Bytes(::core::mem::size_of::<Self>()));
+            const FIELD_NAMES: &'static [&'static str] = &[#(#field_names),*];
+            const FIELD_OFFSETS: &'static [Option<Bytes>] = &[#(Some(Bytes(Self::REPR_C_OFFSETS[#offset_indices]))),*];
+            fn to_byte_vec(self) -> Vec<u8> {
+                let offsets = Self::REPR_C_OFFSETS;
+                let mut bytes = Vec::with_capacity(::core::mem::size_of::<Self>());
+                #(
+                    bytes.resize(offsets[#indices], 0);
+                    let field = self.#field_accessors.to_byte_vec();
+                    debug_assert_eq!(field.len(), ::core::mem::size_of::<#field_types>(),
+                        "field bytes differ from its size in memory");
+                    bytes.extend(field);
+                )*
+                bytes.resize(::core::mem::size_of::<Self>(), 0);
+                bytes
+            }
+            fn stride(&self) -> Bytes {
+                Bytes(::core::mem::size_of::<Self>())
+            }
+            fn field_offset(&self, field_index: usize) -> Option<Bytes> {
+                Self::REPR_C_OFFSETS.get(field_index).copied().map(Bytes)
+            }
+        }
+    }
+}
+
+/// Fields are read as `ToByteVec` writes them, including at their `repr(C)` offsets
+/// with `#[to_byte_vec(repr_c)]`.
+#[proc_macro_derive(FromByteVec)]
+pub fn from_byte_vec_derive(input: TokenStream) -> TokenStream {
+    let syn::DeriveInput {
+        ident,
+        data,
+        generics,
+        attrs,
+        ..
+    } = syn::parse_macro_input!(input as syn::DeriveInput);
+    let syn::Data::Struct(syn::DataStruct { fields, .. }) = data else {
+        return syn::Error::new(ident.span(), "FromByteVec can only be derived for structs")
+            .to_compile_error().into();
+    };
+    let generics = bound_type_parameters(generics, &syn::parse_quote!(FromByteVec));
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+    match repr_c_mode(&attrs, &ident) {
+        Ok(true) => {
+            let field_types: Vec<_> = fields.iter().map(|f| &f.ty).collect();
+            let field_values: Vec<_> = field_types.iter().enumerate().map(|(i, ty)| {
+                quote!(<#ty as FromByteVec>::from_byte_slice(&bytes[Self::REPR_C_OFFSETS[#i]..]))
+            }).collect();
+            let construction = construct_fields(&fields, &field_values);
+            // the offsets are declared by the ToByteVec derive, which FromByteVec requires
+            return quote! {
+                impl #impl_generics FromByteVec for #ident #type_generics #where_clause {
+                    const BYTE_SIZE: usize = ::core::mem::size_of::<Self>();
+                    #[allow(unused_variables)]
+                    fn from_byte_slice(bytes: &[u8]) -> Self {
+                        #construction
+                    }
+                }
+            }
+            .into();
+        },
+        Ok(false) => {},
+        Err(e) => return e.to_compile_error().into(),
+    }
+    let field_types: Vec<_> = fields.iter().map(|f| &f.ty).collect();
+    // each field is read after all of those before it, as they are written by ToByteVec
+    let field_offsets = field_types.iter().enumerate().map(|(i, _)| {
+        let types_before = &field_types[..i];
+        quote!(0 #( + <#types_before as FromByteVec>::BYTE_SIZE )*)
+    });
+    let field_values: Vec<_> = field_types.iter().zip(field_offsets).map(|(ty, offset)| {
+        quote!(<#ty as FromByteVec>::from_byte_slice(&bytes[#offset..]))
+    }).collect();
+    let construction = construct_fields(&fields, &field_values);
+    quote! {
+        impl #impl_generics FromByteVec for #ident #type_generics #where_clause {
+            const BYTE_SIZE: usize = 0 #( + <#field_types as FromByteVec>::BYTE_SIZE )*;
+            #[allow(unused_variables)]
+            fn from_byte_slice(bytes: &[u8]) -> Self {
+                #construction
+            }
+        }
+    }
+    .into()
+}
+
+#[proc_macro_derive(Std140)]
+pub fn std140_derive(input: TokenStream) -> TokenStream {
+    let syn::DeriveInput {
+        ident,
+        data,
+        generics,
+        ..
+    } = syn::parse_macro_input!(input as syn::DeriveInput);
+    let fields = match struct_fields(data, &ident, "Std140") {
+        Ok(fields) => fields,
+        Err(e) => return e.to_compile_error().into()
+    };
+    let generics = bound_type_parameters(generics, &syn::parse_quote!(Std140));
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+    // structs are aligned as at least a vec4
+    let layout_stream = block_layout("Std140", 16, &fields);
+    quote! {
+        impl #impl_generics Std140 for #ident #type_generics #where_clause {
+            #layout_stream
+        }
+    }
+    .into()
+}
+
+#[proc_macro_derive(Std430)]
+pub fn std430_derive(input: TokenStream) -> TokenStream {
+    let syn::DeriveInput {
+        ident,
+        data,
+        generics,
+        ..
+    } = syn::parse_macro_input!(input as syn::DeriveInput);
+    let fields = match struct_fields(data, &ident, "Std430") {
+        Ok(fields) => fields,
+        Err(e) => return e.to_compile_error().into()
+    };
+    // GLSL structs are named after the rust struct, so every instantiation would define the same name
+    if generics.type_params().next().is_some() || generics.const_params().next().is_some() {
+        return syn::Error::new_spanned(&generics, "Std430 cannot be derived for generic structs, \
+            as GLSL structs cannot be generic")
+            .to_compile_error().into();
+    }
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+    let layout_stream = block_layout("Std430", 1, &fields);
+    let field_types: Vec<_> = fields.iter().map(|f| &f.ty).collect();
+    // tuple struct fields are named by index, as in rust
+    let field_names: Vec<_> = fields.iter().enumerate().map(|(i, f)| match &f.ident {
+        Some(ident) => ident.to_string(),
+        None => format!("_{i}")
+    }).collect();
+    let glsl_name = ident.to_string();
+    quote! {
+        impl #impl_generics Std430 for #ident #type_generics #where_clause {
+            #layout_stream
+            fn glsl_type() -> String {
+                #glsl_name.to_owned()
+            }
+            fn glsl_definitions(definitions: &mut Vec<String>) {
+                #( <#field_types as Std430>::glsl_definitions(definitions); )*
+                let members: String = [
+                    #( format!("    {} {};\n", <#field_types as Std430>::glsl_type(), #field_names), )*
+                ].concat();
+                let definition = format!("struct {} {{\n{members}}};\n", #glsl_name);
+                if !definitions.contains(&definition) {
+                    definitions.push(definition);
                 }
-                fn field_offset(&self, field_index: usize) -> Option<Bytes> {
-                    #offset_stream
+            }
+        }
+    }
+    .into()
+}
+
+/// Each field is an input named after it, or `#[owl(name = "...")]`, converted to floating point,
+/// and normalised with `#[owl(normalise)]`, unless it is input as integers with `#[owl(integral)]`,
+/// or as doubles with `#[owl(double)]`.
+/// Offsets are the `FIELD_OFFSETS` of the `ToByteVec` derive, including `#[to_byte_vec(repr_c)]`.
+#[proc_macro_derive(Vertex, attributes(owl))]
+pub fn vertex_derive(input: TokenStream) -> TokenStream {
+    let syn::DeriveInput {
+        ident,
+        data,
+        generics,
+        ..
+    } = syn::parse_macro_input!(input as syn::DeriveInput);
+    let fields = match struct_fields(data, &ident, "Vertex") {
+        Ok(fields) => fields,
+        Err(e) => return e.to_compile_error().into()
+    };
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+    let mut inputs = Vec::with_capacity(fields.len());
+    for (i, field) in fields.iter().enumerate() {
+        let options = match VertexFieldOptions::parse(field) {
+            Ok(options) => options,
+            Err(e) => return e.to_compile_error().into()
+        };
+        // tuple struct fields are named by index, as in rust
+        let name = options.name.unwrap_or_else(|| match &field.ident {
+            Some(ident) => ident.to_string(),
+            None => format!("_{i}")
+        });
+        let ty = &field.ty;
+        inputs.push(if options.integral {
+            quote!(<#ty as ::owl::prelude::IntegralVertexField>::integral_input(#name.to_owned()))
+        } else if options.double {
+            quote!(<#ty as ::owl::prelude::DoubleVertexField>::double_input(#name.to_owned()))
+        } else {
+            let normalise = options.normalise;
+            quote!(<#ty as ::owl::prelude::VertexField>::float_input(#name.to_owned(), #normalise))
+        });
+    }
+    // offsets are those of the ToByteVec layout, which fail to compile if they depend on the value
+    let indices = 0..fields.len();
+    quote! {
+        impl #impl_generics ::owl::prelude::Vertex for #ident #type_generics #where_clause {
+            fn inputs() -> Vec<(::owl::ThinInputAttribute, ::owl::Bytes)> {
+                const {
+                    assert!(<Self as ::owl::prelude::ToByteVec>::STRIDE.is_some(),
+                        "vertices must all be the same size");
                 }
+                vec![ #( (#inputs, <Self as ::owl::prelude::ToByteVec>::field_offset_of::<#indices>()), )* ]
             }
         }
-        .into()
-    } else {
-        quote!().into()
     }
+    .into()
+}
+
+/// The options of a `Vertex` field, from its `#[owl(...)]` attributes
+#[derive(Default)]
+struct VertexFieldOptions {
+    name: Option<String>,
+    normalise: bool,
+    integral: bool,
+    double: bool,
+}
+impl VertexFieldOptions {
+    fn parse(field: &syn::Field) -> Result<Self, syn::Error> {
+        let mut options = Self::default();
+        for attr in field.attrs.iter().filter(|a| a.path().is_ident("owl")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("name") {
+                    options.name = Some(meta.value()?.parse::<syn::LitStr>()?.value());
+                } else if meta.path.is_ident("normalise") {
+                    options.normalise = true;
+                } else if meta.path.is_ident("integral") {
+                    options.integral = true;
+                } else if meta.path.is_ident("double") {
+                    options.double = true;
+                } else {
+                    return Err(meta.error("unknown owl option, expected `name`, `normalise`, `integral` or `double`"));
+                }
+                Ok(())
+            })?;
+        }
+        if options.normalise && options.integral {
+            return Err(syn::Error::new_spanned(field, "integral inputs cannot be normalised"));
+        }
+        if options.double && (options.normalise || options.integral) {
+            return Err(syn::Error::new_spanned(field, "double inputs cannot be normalised or integral"));
+        }
+        Ok(options)
+    }
+}
+
+/// The fields of a struct with at least one field, as is required by GLSL
+fn struct_fields(data: syn::Data, ident: &syn::Ident, derive: &str) -> Result<syn::Fields, syn::Error> {
+    match data {
+        syn::Data::Struct(syn::DataStruct { fields: fields @ (syn::Fields::Named(_) | syn::Fields::Unnamed(_)), .. })
+            if !fields.is_empty() => Ok(fields),
+        _ => Err(syn::Error::new(ident.span(),
+            format!("{derive} can only be derived for structs with at least one field")))
+    }
+}
+
+/// The associated constants and write function of a struct laid out by `layout` (`Std140` or `Std430`),
+/// where the alignment of the struct is at least `minimum_alignment`
+fn block_layout(layout: &str, minimum_alignment: usize, fields: &syn::Fields) -> proc_macro2::TokenStream {
+    let trait_name = quote::format_ident!("{layout}");
+    let alignment = quote::format_ident!("{}_ALIGNMENT", layout.to_uppercase());
+    let size = quote::format_ident!("{}_SIZE", layout.to_uppercase());
+    let write = quote::format_ident!("write_{}", layout.to_lowercase());
+    let read = quote::format_ident!("read_{}", layout.to_lowercase());
+    let field_types: Vec<_> = fields.iter().map(|f| &f.ty).collect();
+    let field_accessors = field_accessors(fields);
+    let field_bindings: Vec<_> = (0..fields.len()).map(|i| quote::format_ident!("field_{i}"))
+        .map(|binding| quote!(#binding)).collect();
+    let construction = construct_fields(fields, &field_bindings);
+    quote! {
+        const #alignment: usize = {
+            let mut alignment: usize = #minimum_alignment;
+            #(
+                if <#field_types as #trait_name>::#alignment > alignment {
+                    alignment = <#field_types as #trait_name>::#alignment;
+                }
+            )*
+            alignment
+        };
+        const #size: usize = {
+            let mut size: usize = 0;
+            #(
+                size = size.next_multiple_of(<#field_types as #trait_name>::#alignment)
+                    + <#field_types as #trait_name>::#size;
+            )*
+            size.next_multiple_of(<Self as #trait_name>::#alignment)
+        };
+        fn #write(&self, bytes: &mut Vec<u8>) {
+            let start = bytes.len();
+            #(
+                bytes.resize(start + (bytes.len() - start)
+                    .next_multiple_of(<#field_types as #trait_name>::#alignment), 0);
+                #trait_name::#write(&self.#field_accessors, bytes);
+            )*
+            bytes.resize(start + <Self as #trait_name>::#size, 0);
+        }
+        #[allow(unused_assignments)]
+        fn #read(bytes: &[u8]) -> Self {
+            let mut offset: usize = 0;
+            #(
+                offset = offset.next_multiple_of(<#field_types as #trait_name>::#alignment);
+                let #field_bindings = <#field_types as #trait_name>::#read(&bytes[offset..]);
+                offset += <#field_types as #trait_name>::#size;
+            )*
+            #construction
+        }
+    }
+}
+
+/// The tokens to construct `Self` from an expression for each field, in order
+fn construct_fields(fields: &syn::Fields, values: &[proc_macro2::TokenStream]) -> proc_macro2::TokenStream {
+    match fields {
+        syn::Fields::Named(_) => {
+            let names = fields.iter().map(|f| &f.ident);
+            quote!(Self { #( #names: #values, )* })
+        },
+        syn::Fields::Unnamed(_) => quote!(Self( #( #values, )* )),
+        syn::Fields::Unit => quote!(Self),
+    }
+}
+
+/// The tokens to access each field on `self`, by name or index
+fn field_accessors(fields: &syn::Fields) -> Vec<proc_macro2::TokenStream> {
+    fields.iter().enumerate().map(|(i, f)| match &f.ident {
+        Some(ident) => quote!(#ident),
+        None => {
+            let index = syn::Index::from(i);
+            quote!(#index)
+        }
+    }).collect()
 }
 
 fn parse_fields_to_offsets(data: &syn::DataStruct) -> proc_macro2::TokenStream {
@@ -44,12 +44,14 @@ fn fallible_main() -> Result<(), Box<dyn std::error::Error>> {
     //
 
     #[allow(clippy::items_after_statements)]
-    #[derive(ToByteVec, Clone)]
+    #[repr(C)]
+    #[derive(ToByteVec, Vertex, Clone)]
+    #[to_byte_vec(repr_c)]
     struct Vertex {
         pos: [f32;2],
+        #[owl(integral)]
         colour: [u8;3],
     }
-    let test_vertex = Vertex {pos: [0.0;2], colour: [0;3]};
     let vertices = vec![
         Vertex { pos: [0.5, -0.5], colour: [0,0,200] },
         Vertex { pos: [-0.5, -0.5], colour: [0,200,0] },
@@ -57,26 +59,10 @@ fn fallible_main() -> Result<(), Box<dyn std::error::Error>> {
     ];
     let vertex_buffer = owl::ArrayBuffer::new(vertices, owl::BufferUsage::StaticDraw)?;
     let index_buffer = owl::ElementBuffer::new(
-        vec![0,1,2], owl::BufferUsage::StaticDraw, owl::IndexType::UnsignedInt)?;
+        &[0_u32,1,2], owl::BufferUsage::StaticDraw)?;
     let vertex_array_object = owl::VertexArray::new()
         .with_indices(index_buffer)
-        .with_input(
-            owl::ThinInputAttribute::Float { name: "pos".to_owned(), glsl_type: owl::ThinFloatAttributeType::Vec2,
-                data_format: owl::FloatVertexFormat::Size2 { normalise: false, data_type: owl::DataTypeUnsized::Float } },
-            owl::AttributePointer { buffer: &vertex_buffer,
-                stride: test_vertex.stride(),
-                offset: test_vertex.field_offset(0).expect("has at least 1 field")
-            }
-        )?
-        .with_input(
-            owl::ThinInputAttribute::Integral { name: "colour".to_owned(), glsl_type: owl::IntegralAttributeType::UVec3,
-                data_format: owl::IntegralVertexFormat::Size3(owl::IntegralDataType::UnsignedByte) },
-            owl::AttributePointer {
-                buffer: &vertex_buffer,
-                stride: test_vertex.stride(),
-                offset: test_vertex.field_offset(1).expect("has at least 2 fields")
-            }
-        )?;
+        .with_vertex_buffer(&vertex_buffer)?;
 
     //
     // Shader Pipeline
@@ -105,7 +91,7 @@ fn fallible_main() -> Result<(), Box<dyn std::error::Error>> {
             .expect("no nul bytes")
         .compile()?;
     
-    let triangle = owl::Mesh { start: 0, count: 3, vertex_array: &vertex_array_object };
+    let triangle = owl::Mesh { start: 0, count: 3, base_vertex: 0, vertex_array: &vertex_array_object };
 
     event_loop.run(|event, elwt| {
         if let Event::WindowEvent {event, ..} = event {